@@ -0,0 +1,431 @@
+//!
+//! EraVM simulation.
+//!
+
+use std::str::FromStr;
+
+///
+/// Declares the EraVM simulations from a single table of addresses, names, and descriptions.
+///
+macro_rules! eravm_simulations {
+    (
+        $(
+            $(#[$variant_meta:meta])*
+            $variant:ident => ($address:expr, $name:literal, $description:literal$(,)?),
+        )+
+    ) => {
+        ///
+        /// EraVM simulation.
+        ///
+        /// Simulations are far calls to the reserved addresses at the top of the 16-bit range,
+        /// which are replaced with EraVM-specific instructions by the compiler.
+        ///
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum EraVMSimulation {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl EraVMSimulation {
+            /// All the simulations, in the descending order of their addresses.
+            pub const ALL: [Self; [$($name),+].len()] = [$(Self::$variant),+];
+
+            ///
+            /// Returns the simulation address.
+            ///
+            pub const fn address(&self) -> u16 {
+                match self {
+                    $(Self::$variant => $address,)+
+                }
+            }
+
+            ///
+            /// Returns the stable name of the simulation.
+            ///
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+
+            ///
+            /// Returns the description of the simulation and its operands.
+            ///
+            pub const fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)+
+                }
+            }
+
+            ///
+            /// Returns the comma-separated list of supported names.
+            ///
+            pub fn supported_values() -> String {
+                Self::ALL.map(|value| value.name()).join(", ")
+            }
+        }
+    };
+}
+
+eravm_simulations! {
+    /// `to_l1(is_first, key, value)`.
+    ToL1 => (
+        crate::address::ERAVM_ADDRESS_TO_L1,
+        "to_l1",
+        "to_l1(is_first, key, value): Sends an L2-to-L1 log with the `key` and `value` words, where `is_first` marks the first message of the transaction.",
+    ),
+    /// `code_address()`.
+    CodeAddress => (
+        crate::address::ERAVM_ADDRESS_CODE_ADDRESS,
+        "code_address",
+        "code_address(): Returns the address of the contract whose code is being executed, which differs from the current address under delegate calls.",
+    ),
+    /// `precompile(input_parameters, ergs)`.
+    Precompile => (
+        crate::address::ERAVM_ADDRESS_PRECOMPILE,
+        "precompile",
+        "precompile(input_parameters, ergs): Calls the precompile of the current address with the packed `input_parameters`, burning `ergs` additionally.",
+    ),
+    /// `meta()`.
+    Meta => (
+        crate::address::ERAVM_ADDRESS_META,
+        "meta",
+        "meta(): Returns the packed VM meta information, such as the pubdata spent and the shard identifiers.",
+    ),
+    /// `mimic_call(callee, mimic, abi_data)`.
+    MimicCall => (
+        crate::address::ERAVM_ADDRESS_MIMIC_CALL,
+        "mimic_call",
+        "mimic_call(callee, mimic, abi_data): Calls `callee` on behalf of `mimic`, which is only allowed for kernel space contracts.",
+    ),
+    /// `system_mimic_call(callee, mimic, abi_data, extra_1, extra_2)`.
+    SystemMimicCall => (
+        crate::address::ERAVM_ADDRESS_SYSTEM_MIMIC_CALL,
+        "system_mimic_call",
+        "system_mimic_call(callee, mimic, abi_data, extra_1, extra_2): Calls `callee` on behalf of `mimic` with the system call flag and two extra ABI registers.",
+    ),
+    /// `mimic_call_byref(callee, mimic)`.
+    MimicCallByRef => (
+        crate::address::ERAVM_ADDRESS_MIMIC_CALL_BYREF,
+        "mimic_call_byref",
+        "mimic_call_byref(callee, mimic): Calls `callee` on behalf of `mimic`, passing the active pointer as the ABI data.",
+    ),
+    /// `system_mimic_call_byref(callee, mimic, extra_1, extra_2)`.
+    SystemMimicCallByRef => (
+        crate::address::ERAVM_ADDRESS_SYSTEM_MIMIC_CALL_BYREF,
+        "system_mimic_call_byref",
+        "system_mimic_call_byref(callee, mimic, extra_1, extra_2): Calls `callee` on behalf of `mimic` with the system call flag, passing the active pointer as the ABI data.",
+    ),
+    /// `raw_far_call(callee, abi_data, output_offset, output_length)`.
+    RawFarCall => (
+        crate::address::ERAVM_ADDRESS_RAW_FAR_CALL,
+        "raw_far_call",
+        "raw_far_call(callee, abi_data, output_offset, output_length): Calls `callee` with the raw `abi_data`, copying the return data to the heap at `output_offset`.",
+    ),
+    /// `raw_far_call_byref(callee, output_offset, output_length)`.
+    RawFarCallByRef => (
+        crate::address::ERAVM_ADDRESS_RAW_FAR_CALL_BYREF,
+        "raw_far_call_byref",
+        "raw_far_call_byref(callee, output_offset, output_length): Calls `callee` passing the active pointer as the ABI data, copying the return data to the heap at `output_offset`.",
+    ),
+    /// `system_call(callee, extra_1, extra_2, extra_3, extra_4)`.
+    SystemCall => (
+        crate::address::ERAVM_ADDRESS_SYSTEM_CALL,
+        "system_call",
+        "system_call(callee, extra_1, extra_2, extra_3, extra_4): Calls `callee` with the system call flag and four extra ABI registers.",
+    ),
+    /// `system_call_byref(callee, extra_1, extra_2, extra_3, extra_4)`.
+    SystemCallByRef => (
+        crate::address::ERAVM_ADDRESS_SYSTEM_CALL_BYREF,
+        "system_call_byref",
+        "system_call_byref(callee, extra_1, extra_2, extra_3, extra_4): Calls `callee` with the system call flag and four extra ABI registers, passing the active pointer as the ABI data.",
+    ),
+    /// `set_context_value_call(value)`.
+    SetContextValueCall => (
+        crate::address::ERAVM_ADDRESS_SET_CONTEXT_VALUE_CALL,
+        "set_context_value_call",
+        "set_context_value_call(value): Sets the `msg.value` context of the next far call.",
+    ),
+    /// `set_pubdata_price(price)`.
+    SetPubdataPrice => (
+        crate::address::ERAVM_ADDRESS_SET_PUBDATA_PRICE,
+        "set_pubdata_price",
+        "set_pubdata_price(price): Sets the price of a pubdata byte in ergs.",
+    ),
+    /// `increment_tx_counter()`.
+    IncrementTxCounter => (
+        crate::address::ERAVM_ADDRESS_INCREMENT_TX_COUNTER,
+        "increment_tx_counter",
+        "increment_tx_counter(): Increments the transaction counter of the bootloader.",
+    ),
+    /// `get_global_ptr_calldata()`.
+    GetGlobalPtrCalldata => (
+        crate::address::ERAVM_ADDRESS_GET_GLOBAL_PTR_CALLDATA,
+        "get_global_ptr_calldata",
+        "get_global_ptr_calldata(): Returns the calldata fat pointer.",
+    ),
+    /// `get_global_call_flags()`.
+    GetGlobalCallFlags => (
+        crate::address::ERAVM_ADDRESS_GET_GLOBAL_CALL_FLAGS,
+        "get_global_call_flags",
+        "get_global_call_flags(): Returns the call flags of the current call.",
+    ),
+    /// `get_global_ptr_return_data()`.
+    GetGlobalPtrReturnData => (
+        crate::address::ERAVM_ADDRESS_GET_GLOBAL_PTR_RETURN_DATA,
+        "get_global_ptr_return_data",
+        "get_global_ptr_return_data(): Returns the fat pointer to the return data of the last far call.",
+    ),
+    /// `event_initialize(header, topic)`.
+    EventInitialize => (
+        crate::address::ERAVM_ADDRESS_EVENT_INITIALIZE,
+        "event_initialize",
+        "event_initialize(header, topic): Starts an event with the `header` word and the first `topic`.",
+    ),
+    /// `event_write(value_1, value_2)`.
+    EventWrite => (
+        crate::address::ERAVM_ADDRESS_EVENT_WRITE,
+        "event_write",
+        "event_write(value_1, value_2): Appends two words to the event being written.",
+    ),
+    /// `active_ptr_load_calldata()`.
+    ActivePtrLoadCalldata => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_LOAD_CALLDATA,
+        "active_ptr_load_calldata",
+        "active_ptr_load_calldata(): Loads the calldata fat pointer into the active pointer.",
+    ),
+    /// `active_ptr_load_return_data()`.
+    ActivePtrLoadReturnData => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_LOAD_RETURN_DATA,
+        "active_ptr_load_return_data",
+        "active_ptr_load_return_data(): Loads the return data fat pointer into the active pointer.",
+    ),
+    /// `active_ptr_add(offset)`.
+    ActivePtrAdd => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_ADD,
+        "active_ptr_add",
+        "active_ptr_add(offset): Increments the offset of the active pointer by `offset`.",
+    ),
+    /// `active_ptr_shrink(length)`.
+    ActivePtrShrink => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_SHRINK,
+        "active_ptr_shrink",
+        "active_ptr_shrink(length): Decreases the length of the active pointer by `length`.",
+    ),
+    /// `active_ptr_pack(data)`.
+    ActivePtrPack => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_PACK,
+        "active_ptr_pack",
+        "active_ptr_pack(data): Packs `data` into the upper 128 bits of the active pointer.",
+    ),
+    /// `multiplication_high_register(operand_1, operand_2)`.
+    MultiplicationHighRegister => (
+        crate::address::ERAVM_ADDRESS_MULTIPLICATION_HIGH_REGISTER,
+        "multiplication_high_register",
+        "multiplication_high_register(operand_1, operand_2): Returns the upper 256 bits of the 512-bit product of the operands.",
+    ),
+    /// `get_global_extra_abi_data(index)`.
+    GetGlobalExtraAbiData => (
+        crate::address::ERAVM_ADDRESS_GET_GLOBAL_EXTRA_ABI_DATA,
+        "get_global_extra_abi_data",
+        "get_global_extra_abi_data(index): Returns the extra ABI data register with `index`.",
+    ),
+    /// `active_ptr_data_load(offset)`.
+    ActivePtrDataLoad => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_DATA_LOAD,
+        "active_ptr_data_load",
+        "active_ptr_data_load(offset): Loads a word from the data of the active pointer at `offset`.",
+    ),
+    /// `active_ptr_data_copy(destination, offset, length)`.
+    ActivePtrDataCopy => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_DATA_COPY,
+        "active_ptr_data_copy",
+        "active_ptr_data_copy(destination, offset, length): Copies `length` bytes of the active pointer data at `offset` to the heap at `destination`.",
+    ),
+    /// `active_ptr_data_size()`.
+    ActivePtrDataSize => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_DATA_SIZE,
+        "active_ptr_data_size",
+        "active_ptr_data_size(): Returns the length of the active pointer data.",
+    ),
+    /// `const_array_declare(index, size)`.
+    ConstArrayDeclare => (
+        crate::address::ERAVM_ADDRESS_CONST_ARRAY_DECLARE,
+        "const_array_declare",
+        "const_array_declare(index, size): Declares the constant array with `index` and `size` elements.",
+    ),
+    /// `const_array_set(index, offset, value)`.
+    ConstArraySet => (
+        crate::address::ERAVM_ADDRESS_CONST_ARRAY_SET,
+        "const_array_set",
+        "const_array_set(index, offset, value): Sets the element at `offset` of the constant array with `index` to `value`.",
+    ),
+    /// `const_array_finalize(index)`.
+    ConstArrayFinalize => (
+        crate::address::ERAVM_ADDRESS_CONST_ARRAY_FINALIZE,
+        "const_array_finalize",
+        "const_array_finalize(index): Finalizes the constant array with `index`, making it immutable.",
+    ),
+    /// `const_array_get(index, offset)`.
+    ConstArrayGet => (
+        crate::address::ERAVM_ADDRESS_CONST_ARRAY_GET,
+        "const_array_get",
+        "const_array_get(index, offset): Returns the element at `offset` of the constant array with `index`.",
+    ),
+    /// `decommit(versioned_hash, ergs)`.
+    Decommit => (
+        crate::address::ERAVM_ADDRESS_DECOMMIT,
+        "decommit",
+        "decommit(versioned_hash, ergs): Decommits the code with `versioned_hash`, burning `ergs`, and returns the fat pointer to it.",
+    ),
+    /// `active_ptr_load_decommit(versioned_hash, ergs)`.
+    ActivePtrLoadDecommit => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_LOAD_DECOMMIT,
+        "active_ptr_load_decommit",
+        "active_ptr_load_decommit(versioned_hash, ergs): Decommits the code with `versioned_hash`, burning `ergs`, and loads the fat pointer to it into the active pointer.",
+    ),
+    /// `return_forward()`.
+    ReturnForward => (
+        crate::address::ERAVM_ADDRESS_RETURN_FORWARD,
+        "return_forward",
+        "return_forward(): Returns from the current call, forwarding the active pointer data.",
+    ),
+    /// `revert_forward()`.
+    RevertForward => (
+        crate::address::ERAVM_ADDRESS_REVERT_FORWARD,
+        "revert_forward",
+        "revert_forward(): Reverts the current call, forwarding the active pointer data.",
+    ),
+    /// `active_ptr_swap(index_1, index_2)`.
+    ActivePtrSwap => (
+        crate::address::ERAVM_ADDRESS_ACTIVE_PTR_SWAP,
+        "active_ptr_swap",
+        "active_ptr_swap(index_1, index_2): Swaps the active pointers with `index_1` and `index_2`.",
+    ),
+}
+
+impl EraVMSimulation {
+    ///
+    /// Returns an iterator over all the simulations.
+    ///
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+}
+
+/// Checks that the simulation addresses do not collide.
+const _: () = {
+    let mut index = 0;
+    while index < EraVMSimulation::ALL.len() {
+        let mut other = index + 1;
+        while other < EraVMSimulation::ALL.len() {
+            assert!(
+                EraVMSimulation::ALL[index].address() != EraVMSimulation::ALL[other].address(),
+                "EraVM simulation addresses collide"
+            );
+            other += 1;
+        }
+        index += 1;
+    }
+};
+
+impl From<EraVMSimulation> for u16 {
+    fn from(simulation: EraVMSimulation) -> Self {
+        simulation.address()
+    }
+}
+
+impl TryFrom<u16> for EraVMSimulation {
+    type Error = anyhow::Error;
+
+    fn try_from(address: u16) -> Result<Self, Self::Error> {
+        Self::iter()
+            .find(|simulation| simulation.address() == address)
+            .ok_or_else(|| anyhow::anyhow!("Address `0x{address:04x}` is not an EraVM simulation"))
+    }
+}
+
+impl FromStr for EraVMSimulation {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|simulation| simulation.name() == string)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown EraVM simulation `{string}`. Supported values: {}",
+                    Self::supported_values(),
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for EraVMSimulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn addresses() {
+        let addresses = super::EraVMSimulation::iter()
+            .map(u16::from)
+            .collect::<BTreeSet<u16>>();
+
+        assert_eq!(addresses.len(), super::EraVMSimulation::ALL.len());
+        assert_eq!(
+            addresses.first().copied(),
+            Some(crate::address::ERAVM_ADDRESS_ACTIVE_PTR_SWAP)
+        );
+        assert_eq!(
+            addresses.last().copied(),
+            Some(crate::address::ERAVM_ADDRESS_TO_L1)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for simulation in super::EraVMSimulation::iter() {
+            assert_eq!(
+                super::EraVMSimulation::try_from(simulation.address()).expect("Always valid"),
+                simulation
+            );
+            assert_eq!(
+                simulation
+                    .to_string()
+                    .parse::<super::EraVMSimulation>()
+                    .expect("Always valid"),
+                simulation
+            );
+            assert!(simulation.description().starts_with(simulation.name()));
+        }
+    }
+
+    #[test]
+    fn error_unknown() {
+        assert!(super::EraVMSimulation::try_from(0x8006).is_err());
+        assert!("unknown".parse::<super::EraVMSimulation>().is_err());
+    }
+
+    #[test]
+    fn error_supported_values() {
+        let error = "unknown"
+            .parse::<super::EraVMSimulation>()
+            .expect_err("Always invalid")
+            .to_string();
+
+        assert_eq!(
+            error,
+            format!(
+                "Unknown EraVM simulation `unknown`. Supported values: {}",
+                super::EraVMSimulation::supported_values()
+            )
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//!
+//! EraVM system contract.
+//!
+
+use std::str::FromStr;
+
+use crate::address::Address;
+
+///
+/// Declares the EraVM system contracts from a single table of addresses, names, and descriptions.
+///
+macro_rules! eravm_system_contracts {
+    (
+        $(
+            $(#[$variant_meta:meta])*
+            $variant:ident => ($address:literal, $name:literal, $description:literal$(,)?),
+        )+
+    ) => {
+        ///
+        /// EraVM system contract.
+        ///
+        /// Includes the precompiles at the Ethereum addresses, the system contracts in the kernel space
+        /// starting from `KERNEL_SPACE_OFFSET`, and the well-known contracts deployed right above it.
+        ///
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum EraVMSystemContract {
+            $(
+                $(#[$variant_meta])*
+                #[doc = concat!("`", $name, "` at `", stringify!($address), "`.")]
+                $variant,
+            )+
+        }
+
+        impl EraVMSystemContract {
+            /// All the system contracts, in the ascending order of their addresses.
+            pub const ALL: [Self; [$($name),+].len()] = [$(Self::$variant),+];
+
+            ///
+            /// Returns the address as an integer.
+            ///
+            pub const fn address_value(&self) -> u32 {
+                match self {
+                    $(Self::$variant => $address,)+
+                }
+            }
+
+            ///
+            /// Returns the contract name.
+            ///
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+
+            ///
+            /// Returns the description of the contract purpose.
+            ///
+            pub const fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)+
+                }
+            }
+
+            ///
+            /// Returns the comma-separated list of supported names.
+            ///
+            pub fn supported_values() -> String {
+                Self::ALL.map(|value| value.name()).join(", ")
+            }
+        }
+    };
+}
+
+eravm_system_contracts! {
+    ECRecover => (0x1, "ECRecover", "Recovers the signer address of an ECDSA signature."),
+    Sha256 => (0x2, "SHA256", "Computes the SHA-256 hash."),
+    Ripemd160 => (0x3, "RIPEMD160", "Computes the RIPEMD-160 hash."),
+    Identity => (0x4, "Identity", "Returns the input data."),
+    ModExp => (0x5, "ModExp", "Computes the modular exponentiation."),
+    ECAdd => (0x6, "ECAdd", "Adds two points on the alt_bn128 curve."),
+    ECMul => (0x7, "ECMul", "Multiplies a point on the alt_bn128 curve by a scalar."),
+    ECPairing => (0x8, "ECPairing", "Checks the alt_bn128 pairing equation."),
+    Blake2F => (0x9, "Blake2F", "Computes the BLAKE2 compression function."),
+    P256Verify => (0x100, "P256Verify", "Verifies a signature on the secp256r1 (P-256) curve."),
+    Bootloader => (0x8001, "Bootloader", "The formal address of the bootloader, which processes the transactions of a batch."),
+    AccountCodeStorage => (0x8002, "AccountCodeStorage", "Stores the versioned bytecode hashes of the deployed contracts."),
+    NonceHolder => (0x8003, "NonceHolder", "Stores the transaction and deployment nonces of the accounts."),
+    KnownCodesStorage => (0x8004, "KnownCodesStorage", "Marks the bytecode hashes as known, allowing the contracts to be deployed."),
+    ImmutableSimulator => (0x8005, "ImmutableSimulator", "Stores the immutable values of the deployed contracts."),
+    ContractDeployer => (0x8006, "ContractDeployer", "Deploys the contracts and derives their addresses."),
+    ForceDeployer => (0x8007, "ForceDeployer", "The formal address allowed to force deployments during system upgrades."),
+    L1Messenger => (0x8008, "L1Messenger", "Sends the L2-to-L1 messages and publishes the pubdata."),
+    MsgValueSimulator => (0x8009, "MsgValueSimulator", "Transfers the base token along with the calls with non-zero `msg.value`."),
+    L2BaseToken => (0x800a, "L2BaseToken", "Holds the balances of the base token."),
+    SystemContext => (0x800b, "SystemContext", "Provides the block and transaction context, such as `block.number` and `tx.gasprice`."),
+    BootloaderUtilities => (0x800c, "BootloaderUtilities", "Computes the transaction hashes for the bootloader."),
+    EventWriter => (0x800d, "EventWriter", "Emits the events on behalf of the contracts."),
+    Compressor => (0x800e, "Compressor", "Validates the compressed bytecode and state diffs."),
+    ComplexUpgrader => (0x800f, "ComplexUpgrader", "Executes the delegated upgrade logic during system upgrades."),
+    Keccak256 => (0x8010, "Keccak256", "Computes the Keccak-256 hash."),
+    PubdataChunkPublisher => (0x8011, "PubdataChunkPublisher", "Publishes the pubdata chunks as blobs."),
+    CodeOracle => (0x8012, "CodeOracle", "Returns the bytecode by its versioned hash."),
+    EVMGasManager => (0x8013, "EvmGasManager", "Tracks the gas and warm storage of the EVM emulator."),
+    EVMPredeploysManager => (0x8014, "EvmPredeploysManager", "Deploys the well-known EVM contracts at their canonical addresses."),
+    EVMHashesStorage => (0x8015, "EvmHashesStorage", "Stores the Keccak-256 hashes of the EVM bytecode."),
+    Create2Factory => (0x10000, "Create2Factory", "Deploys the contracts with deterministic `create2` addresses on all ZKsync chains."),
+}
+
+impl EraVMSystemContract {
+    /// The offset of the system contracts in the kernel space.
+    pub const KERNEL_SPACE_OFFSET: u32 = 0x8000;
+
+    ///
+    /// Returns the contract address.
+    ///
+    pub fn address(&self) -> Address {
+        let mut bytes = [0_u8; crate::BYTE_LENGTH_ETH_ADDRESS];
+        bytes[crate::BYTE_LENGTH_ETH_ADDRESS - std::mem::size_of::<u32>()..]
+            .copy_from_slice(self.address_value().to_be_bytes().as_slice());
+        Address::new(bytes)
+    }
+
+    ///
+    /// Whether the contract is an Ethereum precompile.
+    ///
+    pub const fn is_precompile(&self) -> bool {
+        self.address_value() < Self::KERNEL_SPACE_OFFSET
+    }
+
+    ///
+    /// Whether the contract is in the kernel space.
+    ///
+    pub const fn is_kernel_space(&self) -> bool {
+        self.address_value() <= u16::MAX as u32
+    }
+
+    ///
+    /// Returns an iterator over all the system contracts.
+    ///
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+}
+
+/// Checks that the system contract addresses are unique and sorted.
+const _: () = {
+    let mut index = 1;
+    while index < EraVMSystemContract::ALL.len() {
+        assert!(
+            EraVMSystemContract::ALL[index - 1].address_value()
+                < EraVMSystemContract::ALL[index].address_value(),
+            "EraVM system contract addresses must be unique and sorted"
+        );
+        index += 1;
+    }
+};
+
+impl From<EraVMSystemContract> for Address {
+    fn from(contract: EraVMSystemContract) -> Self {
+        contract.address()
+    }
+}
+
+impl TryFrom<Address> for EraVMSystemContract {
+    type Error = anyhow::Error;
+
+    fn try_from(address: Address) -> Result<Self, Self::Error> {
+        Self::iter()
+            .find(|contract| contract.address() == address)
+            .ok_or_else(|| anyhow::anyhow!("Address `{address}` is not an EraVM system contract"))
+    }
+}
+
+impl FromStr for EraVMSystemContract {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|contract| contract.name() == string)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown EraVM system contract `{string}`. Supported values: {}",
+                    Self::supported_values(),
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for EraVMSystemContract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::address::Address;
+
+    #[test]
+    fn addresses() {
+        assert_eq!(
+            super::EraVMSystemContract::ContractDeployer.address(),
+            Address::from(0x8006)
+        );
+        assert_eq!(
+            super::EraVMSystemContract::ECRecover.address(),
+            Address::from(0x01)
+        );
+        assert_eq!(
+            super::EraVMSystemContract::P256Verify.address(),
+            Address::from(0x100)
+        );
+        assert_eq!(
+            super::EraVMSystemContract::Create2Factory
+                .address()
+                .to_string(),
+            "0x0000000000000000000000000000000000010000"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for contract in super::EraVMSystemContract::iter() {
+            assert_eq!(
+                super::EraVMSystemContract::try_from(contract.address()).expect("Always valid"),
+                contract
+            );
+            assert_eq!(
+                contract
+                    .to_string()
+                    .parse::<super::EraVMSystemContract>()
+                    .expect("Always valid"),
+                contract
+            );
+        }
+    }
+
+    #[test]
+    fn kinds() {
+        let precompiles = super::EraVMSystemContract::iter()
+            .filter(super::EraVMSystemContract::is_precompile)
+            .count();
+        let kernel_space = super::EraVMSystemContract::iter()
+            .filter(super::EraVMSystemContract::is_kernel_space)
+            .count();
+
+        assert_eq!(precompiles, 10);
+        assert_eq!(kernel_space, super::EraVMSystemContract::ALL.len() - 1);
+    }
+
+    #[test]
+    fn error_unknown() {
+        assert!(super::EraVMSystemContract::try_from(Address::from(0x8000)).is_err());
+        assert!("Unknown".parse::<super::EraVMSystemContract>().is_err());
+    }
+
+    #[test]
+    fn error_supported_values() {
+        let error = "Unknown"
+            .parse::<super::EraVMSystemContract>()
+            .expect_err("Always invalid")
+            .to_string();
+
+        assert!(error.starts_with("Unknown EraVM system contract `Unknown`. Supported values: "));
+        assert!(error.ends_with(super::EraVMSystemContract::supported_values().as_str()));
+        assert!(error.contains("P256Verify"));
+    }
+}
@@ -0,0 +1,331 @@
+//!
+//! Bytecode metadata utilities.
+//!
+
+use crate::cbor::CBOR;
+use crate::hash::Hash;
+
+///
+/// Layout of the CBOR metadata trailer in bytecode.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytecodeLayout {
+    /// The code consists of an odd number of 32-byte words, and the trailer is padded with zeros
+    /// from the left to an even number of words, so the bytecode keeps an odd number of words.
+    EraVM,
+    /// The trailer directly follows the code.
+    EVM,
+}
+
+///
+/// Splits `bytecode` into the code and the CBOR metadata trailer.
+///
+/// The trailer is `None` if there is no decodable CBOR payload at the end of `bytecode`.
+/// For the EraVM layout, the zero padding between the code and the trailer is excluded from the code.
+/// As the padding length is determined by the trailer length, a zero word at the end of the code is kept.
+/// If the bytecode does not follow the EraVM layout, the code is everything before the trailer.
+///
+pub fn split_metadata(bytecode: &[u8], layout: BytecodeLayout) -> (&[u8], Option<&[u8]>) {
+    let Some(trailer) = CBOR::trailer(bytecode)
+        .ok()
+        .filter(|trailer| CBOR::from_slice(trailer).is_ok())
+    else {
+        return (bytecode, None);
+    };
+    let trailer_offset = bytecode.len() - trailer.len();
+
+    let code_length = match layout {
+        BytecodeLayout::EraVM => trailer_offset
+            .checked_sub(eravm_padding_length(trailer.len()))
+            .filter(|code_length| {
+                is_eravm_code_length(*code_length)
+                    && bytecode[*code_length..trailer_offset]
+                        .iter()
+                        .all(|byte| *byte == 0)
+            })
+            .unwrap_or(trailer_offset),
+        BytecodeLayout::EVM => trailer_offset,
+    };
+    (&bytecode[..code_length], Some(trailer))
+}
+
+///
+/// Returns `bytecode` without the CBOR metadata trailer.
+///
+/// See `split_metadata` for details.
+///
+pub fn strip_metadata(bytecode: &[u8], layout: BytecodeLayout) -> &[u8] {
+    split_metadata(bytecode, layout).0
+}
+
+///
+/// Replaces the hash in the CBOR metadata trailer of `bytecode`.
+///
+/// The hash type key is derived from `hash`, and the trailer length suffix is recomputed.
+/// For the EraVM layout, the padding is recomputed from the new trailer length.
+///
+pub fn replace_metadata_hash(
+    bytecode: &[u8],
+    layout: BytecodeLayout,
+    hash: &Hash,
+) -> anyhow::Result<Vec<u8>> {
+    let hash_type = hash
+        .metadata_hash_type()
+        .ok_or_else(|| anyhow::anyhow!("The hash `{hash}` cannot be used as the metadata hash"))?;
+
+    let (code, trailer) = split_metadata(bytecode, layout);
+    let trailer =
+        trailer.ok_or_else(|| anyhow::anyhow!("The bytecode has no CBOR metadata trailer"))?;
+
+    let cbor = CBOR::from_slice(trailer)?;
+    if cbor.hash.is_none() {
+        anyhow::bail!("The CBOR metadata trailer has no hash");
+    }
+    let cbor = CBOR {
+        hash: Some((hash_type.as_str(), hash.as_bytes())),
+        version_key: cbor.version_key,
+        version_data: cbor.version_data,
+        layout: cbor.layout,
+        extra_fields: cbor.extra_fields,
+    };
+    let trailer = cbor.try_to_vec()?;
+
+    if let BytecodeLayout::EraVM = layout {
+        if !is_eravm_code_length(code.len()) {
+            anyhow::bail!(
+                "The EraVM code size {} is not an odd number of {}-byte words",
+                code.len(),
+                crate::BYTE_LENGTH_FIELD
+            );
+        }
+    }
+
+    let mut result = Vec::with_capacity(code.len() + trailer.len() + 2 * crate::BYTE_LENGTH_FIELD);
+    result.extend_from_slice(code);
+    if let BytecodeLayout::EraVM = layout {
+        result.resize(result.len() + eravm_padding_length(trailer.len()), 0);
+    }
+    result.extend(trailer);
+    Ok(result)
+}
+
+///
+/// Returns the length of the zero padding that extends an EraVM trailer to an even number of words.
+///
+fn eravm_padding_length(trailer_length: usize) -> usize {
+    let mut word_count = trailer_length.div_ceil(crate::BYTE_LENGTH_FIELD);
+    if word_count % 2 == 1 {
+        word_count += 1;
+    }
+    word_count * crate::BYTE_LENGTH_FIELD - trailer_length
+}
+
+///
+/// Whether `length` is a valid EraVM code length, that is, an odd number of words.
+///
+fn is_eravm_code_length(length: usize) -> bool {
+    length % crate::BYTE_LENGTH_FIELD == 0 && (length / crate::BYTE_LENGTH_FIELD) % 2 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cbor::CBOR;
+    use crate::hash::ipfs::IPFS;
+    use crate::hash::keccak256::Keccak256;
+    use crate::hash::sha256::Sha256;
+    use crate::hash::Hash;
+    use crate::metadata_hash_type::MetadataHashType;
+
+    use super::BytecodeLayout;
+
+    fn ipfs_trailer(preimage: &str) -> Vec<u8> {
+        let hash = IPFS::from_slice(preimage.as_bytes());
+        CBOR::new(
+            Some((MetadataHashType::IPFS.to_string(), hash.as_bytes())),
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        )
+        .to_vec()
+    }
+
+    #[test]
+    fn evm() {
+        let code = vec![0x60_u8, 0x80, 0x60, 0x40, 0x52];
+        let trailer = ipfs_trailer("LLVM is the Endgame");
+        let mut bytecode = code.clone();
+        bytecode.extend(trailer.as_slice());
+
+        assert_eq!(
+            super::split_metadata(bytecode.as_slice(), BytecodeLayout::EVM),
+            (code.as_slice(), Some(trailer.as_slice()))
+        );
+        assert_eq!(
+            super::strip_metadata(bytecode.as_slice(), BytecodeLayout::EVM),
+            code.as_slice()
+        );
+
+        let hash = Hash::IPFS(IPFS::from_slice("LLVM is the Beginning".as_bytes()));
+        let replaced =
+            super::replace_metadata_hash(bytecode.as_slice(), BytecodeLayout::EVM, &hash)
+                .expect("Always valid");
+        let mut expected = code.clone();
+        expected.extend(ipfs_trailer("LLVM is the Beginning"));
+        assert_eq!(replaced, expected);
+    }
+
+    #[test]
+    fn eravm() {
+        let code = vec![0xAA_u8; 3 * crate::BYTE_LENGTH_FIELD];
+        let trailer = ipfs_trailer("LLVM is the Endgame");
+        assert_eq!(trailer.len(), 2 * crate::BYTE_LENGTH_FIELD);
+        let mut bytecode = code.clone();
+        bytecode.extend(trailer.as_slice());
+
+        assert_eq!(
+            super::split_metadata(bytecode.as_slice(), BytecodeLayout::EraVM),
+            (code.as_slice(), Some(trailer.as_slice()))
+        );
+
+        let hash = Hash::IPFS(IPFS::from_slice("LLVM is the Beginning".as_bytes()));
+        let replaced =
+            super::replace_metadata_hash(bytecode.as_slice(), BytecodeLayout::EraVM, &hash)
+                .expect("Always valid");
+        assert_eq!(replaced.len(), bytecode.len());
+        assert_eq!(
+            super::strip_metadata(replaced.as_slice(), BytecodeLayout::EraVM),
+            code.as_slice()
+        );
+        assert_eq!(
+            CBOR::from_bytecode(replaced.as_slice())
+                .expect("Always valid")
+                .hash
+                .map(|(_, hash)| hash.to_vec()),
+            Some(hash.as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn eravm_padding() {
+        let code = vec![0xAA_u8; crate::BYTE_LENGTH_FIELD];
+        let trailer = CBOR::<'_, String>::new(
+            None,
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        )
+        .to_vec();
+        let mut bytecode = code.clone();
+        bytecode.extend(vec![0_u8; 2 * crate::BYTE_LENGTH_FIELD - trailer.len()]);
+        bytecode.extend(trailer.as_slice());
+
+        assert_eq!(
+            super::split_metadata(bytecode.as_slice(), BytecodeLayout::EraVM),
+            (code.as_slice(), Some(trailer.as_slice()))
+        );
+    }
+
+    #[test]
+    fn eravm_trailer_length_changed() {
+        let code = vec![0xAA_u8; 3 * crate::BYTE_LENGTH_FIELD];
+        let mut bytecode = code.clone();
+        bytecode.extend(ipfs_trailer("LLVM is the Endgame"));
+
+        let keccak256 = Hash::Keccak256(Keccak256::from_slice("LLVM is the Endgame".as_bytes()));
+        let replaced =
+            super::replace_metadata_hash(bytecode.as_slice(), BytecodeLayout::EraVM, &keccak256)
+                .expect("Always valid");
+        assert_eq!(replaced.len(), 7 * crate::BYTE_LENGTH_FIELD);
+        assert_eq!(
+            super::strip_metadata(replaced.as_slice(), BytecodeLayout::EraVM),
+            code.as_slice()
+        );
+        let cbor = CBOR::from_bytecode(replaced.as_slice()).expect("Always valid");
+        assert_eq!(
+            cbor.hash,
+            Some((
+                MetadataHashType::Keccak256.to_string(),
+                keccak256.as_bytes()
+            ))
+        );
+
+        let ipfs = Hash::IPFS(IPFS::from_slice("LLVM is the Endgame".as_bytes()));
+        let restored =
+            super::replace_metadata_hash(replaced.as_slice(), BytecodeLayout::EraVM, &ipfs)
+                .expect("Always valid");
+        assert_eq!(restored, bytecode);
+    }
+
+    #[test]
+    fn eravm_zero_code_word() {
+        let mut code = vec![0xAA_u8; 2 * crate::BYTE_LENGTH_FIELD];
+        code.extend(vec![0_u8; crate::BYTE_LENGTH_FIELD]);
+        let mut bytecode = code.clone();
+        bytecode.extend(ipfs_trailer("LLVM is the Endgame"));
+
+        assert_eq!(
+            super::strip_metadata(bytecode.as_slice(), BytecodeLayout::EraVM),
+            code.as_slice()
+        );
+
+        let keccak256 = Hash::Keccak256(Keccak256::from_slice("LLVM is the Endgame".as_bytes()));
+        let replaced =
+            super::replace_metadata_hash(bytecode.as_slice(), BytecodeLayout::EraVM, &keccak256)
+                .expect("Always valid");
+        assert_eq!(
+            super::strip_metadata(replaced.as_slice(), BytecodeLayout::EraVM),
+            code.as_slice()
+        );
+
+        let ipfs = Hash::IPFS(IPFS::from_slice("LLVM is the Endgame".as_bytes()));
+        let restored =
+            super::replace_metadata_hash(replaced.as_slice(), BytecodeLayout::EraVM, &ipfs)
+                .expect("Always valid");
+        assert_eq!(restored, bytecode);
+    }
+
+    #[test]
+    fn eravm_invalid_code_length() {
+        let code = vec![0xAA_u8; 2 * crate::BYTE_LENGTH_FIELD];
+        let mut bytecode = code.clone();
+        bytecode.extend(ipfs_trailer("LLVM is the Endgame"));
+
+        assert_eq!(
+            super::strip_metadata(bytecode.as_slice(), BytecodeLayout::EraVM),
+            code.as_slice()
+        );
+        assert!(super::replace_metadata_hash(
+            bytecode.as_slice(),
+            BytecodeLayout::EraVM,
+            &Hash::IPFS(IPFS::from_slice(&[]))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_hash_type() {
+        let mut bytecode = vec![0xAA_u8; crate::BYTE_LENGTH_FIELD];
+        bytecode.extend(ipfs_trailer("LLVM is the Endgame"));
+
+        assert!(super::replace_metadata_hash(
+            bytecode.as_slice(),
+            BytecodeLayout::EVM,
+            &Hash::Sha256(Sha256::from_slice(&[]))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn no_metadata() {
+        let bytecode = vec![0xAA_u8; crate::BYTE_LENGTH_FIELD];
+
+        assert_eq!(
+            super::split_metadata(bytecode.as_slice(), BytecodeLayout::EraVM),
+            (bytecode.as_slice(), None)
+        );
+        assert!(super::replace_metadata_hash(
+            bytecode.as_slice(),
+            BytecodeLayout::EraVM,
+            &Hash::IPFS(IPFS::from_slice(&[]))
+        )
+        .is_err());
+    }
+}
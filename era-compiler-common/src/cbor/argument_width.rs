@@ -0,0 +1,73 @@
+//!
+//! CBOR argument width.
+//!
+
+///
+/// CBOR argument width.
+///
+/// The argument of a data item head is either stored in the initial byte, or follows it
+/// as a big-endian integer of 1, 2, 4, or 8 bytes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ArgumentWidth {
+    /// The argument is stored in the initial byte.
+    Immediate,
+    /// The argument is a 1-byte integer.
+    U8,
+    /// The argument is a 2-byte integer.
+    U16,
+    /// The argument is a 4-byte integer.
+    U32,
+    /// The argument is an 8-byte integer.
+    U64,
+}
+
+impl ArgumentWidth {
+    /// The maximum argument that can be stored in the initial byte.
+    pub const IMMEDIATE_MAX: u64 = 23;
+
+    ///
+    /// Returns the smallest width that can represent `argument`.
+    ///
+    pub fn minimal(argument: u64) -> Self {
+        if argument <= Self::IMMEDIATE_MAX {
+            Self::Immediate
+        } else if argument <= u8::MAX as u64 {
+            Self::U8
+        } else if argument <= u16::MAX as u64 {
+            Self::U16
+        } else if argument <= u32::MAX as u64 {
+            Self::U32
+        } else {
+            Self::U64
+        }
+    }
+
+    ///
+    /// Returns the additional information value of the initial byte.
+    ///
+    /// For `Immediate`, the argument itself is returned.
+    ///
+    pub fn additional_information(&self, argument: u64) -> u8 {
+        match self {
+            Self::Immediate => argument as u8,
+            Self::U8 => 24,
+            Self::U16 => 25,
+            Self::U32 => 26,
+            Self::U64 => 27,
+        }
+    }
+
+    ///
+    /// Returns the number of bytes following the initial byte.
+    ///
+    pub fn byte_length(&self) -> usize {
+        match self {
+            Self::Immediate => 0,
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U32 => 4,
+            Self::U64 => 8,
+        }
+    }
+}
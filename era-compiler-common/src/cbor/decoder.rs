@@ -0,0 +1,165 @@
+//!
+//! CBOR decoder.
+//!
+
+use crate::cbor::major_type::MajorType;
+use crate::cbor::value::Value;
+
+///
+/// CBOR decoder.
+///
+/// Supports the subset of CBOR used in contract metadata: definite-length data items
+/// with arguments of up to 8 bytes.
+///
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    /// The data being decoded.
+    data: &'a [u8],
+    /// The offset of the next unread byte.
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    ///
+    /// Whether all the data has been consumed.
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.offset == self.data.len()
+    }
+
+    ///
+    /// Returns the major type of the next data item without consuming it.
+    ///
+    pub fn peek_major_type(&self) -> anyhow::Result<MajorType> {
+        self.data
+            .get(self.offset)
+            .copied()
+            .map(MajorType::from)
+            .ok_or_else(|| anyhow::anyhow!("Unexpected end of CBOR data at offset {}", self.offset))
+    }
+
+    ///
+    /// Reads the head of the next data item, returning its major type and argument.
+    ///
+    pub fn read_head(&mut self) -> anyhow::Result<(MajorType, u64)> {
+        let major_type = self.peek_major_type()?;
+        let additional_information =
+            self.data[self.offset] & MajorType::ADDITIONAL_INFORMATION_MASK;
+        self.offset += 1;
+
+        let argument = match additional_information {
+            value @ 0..=23 => value as u64,
+            24 => self.read_bytes(1)?[0] as u64,
+            25 => u16::from_be_bytes(self.read_bytes(2)?.try_into().expect("Always valid")) as u64,
+            26 => u32::from_be_bytes(self.read_bytes(4)?.try_into().expect("Always valid")) as u64,
+            27 => u64::from_be_bytes(self.read_bytes(8)?.try_into().expect("Always valid")),
+            31 => anyhow::bail!(
+                "Indefinite-length CBOR {major_type} at offset {} is not supported",
+                self.offset - 1
+            ),
+            value => anyhow::bail!(
+                "Reserved CBOR additional information value {value} at offset {}",
+                self.offset - 1
+            ),
+        };
+        Ok((major_type, argument))
+    }
+
+    ///
+    /// Reads a map head, returning the number of key-value pairs.
+    ///
+    pub fn read_map_length(&mut self) -> anyhow::Result<usize> {
+        let length = self.read_head_of_type(MajorType::Map)?;
+        usize::try_from(length)
+            .map_err(|_| anyhow::anyhow!("CBOR map length {length} is too large"))
+    }
+
+    ///
+    /// Reads a byte string.
+    ///
+    pub fn read_byte_string(&mut self) -> anyhow::Result<&'a [u8]> {
+        let length = self.read_head_of_type(MajorType::ByteString)?;
+        self.read_bytes(length)
+    }
+
+    ///
+    /// Reads a UTF-8 text string.
+    ///
+    pub fn read_text_string(&mut self) -> anyhow::Result<&'a str> {
+        let offset = self.offset;
+        let length = self.read_head_of_type(MajorType::TextString)?;
+        let bytes = self.read_bytes(length)?;
+        std::str::from_utf8(bytes).map_err(|error| {
+            anyhow::anyhow!("Invalid CBOR text string at offset {offset}: {error}")
+        })
+    }
+
+    ///
+    /// Reads a boolean simple value.
+    ///
+    pub fn read_bool(&mut self) -> anyhow::Result<bool> {
+        let offset = self.offset;
+        match self.read_head_of_type(MajorType::SimpleOrFloat)? {
+            argument if argument == MajorType::SIMPLE_FALSE as u64 => Ok(false),
+            argument if argument == MajorType::SIMPLE_TRUE as u64 => Ok(true),
+            argument => anyhow::bail!(
+                "Expected CBOR boolean at offset {offset}, found simple value {argument}"
+            ),
+        }
+    }
+
+    ///
+    /// Reads a metadata field value.
+    ///
+    pub fn read_value(&mut self) -> anyhow::Result<Value> {
+        let offset = self.offset;
+        match self.peek_major_type()? {
+            MajorType::TextString => Ok(Value::Text(self.read_text_string()?.to_owned())),
+            MajorType::ByteString => Ok(Value::Bytes(self.read_byte_string()?.to_vec())),
+            MajorType::SimpleOrFloat => Ok(Value::Bool(self.read_bool()?)),
+            MajorType::UnsignedInteger => Ok(Value::Unsigned(self.read_head()?.1)),
+            major_type => {
+                anyhow::bail!("Unsupported CBOR {major_type} value at offset {offset}")
+            }
+        }
+    }
+
+    ///
+    /// Reads the head of the next data item, checking that it has the `expected` major type.
+    ///
+    fn read_head_of_type(&mut self, expected: MajorType) -> anyhow::Result<u64> {
+        let offset = self.offset;
+        let (major_type, argument) = self.read_head()?;
+        if major_type != expected {
+            anyhow::bail!("Expected CBOR {expected} at offset {offset}, found {major_type}");
+        }
+        Ok(argument)
+    }
+
+    ///
+    /// Reads `length` raw bytes.
+    ///
+    fn read_bytes(&mut self, length: u64) -> anyhow::Result<&'a [u8]> {
+        let end = usize::try_from(length)
+            .ok()
+            .and_then(|length| self.offset.checked_add(length))
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unexpected end of CBOR data: {length} bytes requested at offset {}, but only {} are available",
+                    self.offset,
+                    self.data.len() - self.offset,
+                )
+            })?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+}
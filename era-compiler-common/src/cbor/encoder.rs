@@ -0,0 +1,198 @@
+//!
+//! CBOR encoder.
+//!
+
+use crate::cbor::argument_width::ArgumentWidth;
+use crate::cbor::major_type::MajorType;
+use crate::cbor::value::Value;
+
+///
+/// CBOR encoder.
+///
+/// Writes definite-length data items, selecting the smallest argument width unless
+/// a wider one is requested explicitly.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Encoder {
+    /// The output buffer.
+    buffer: Vec<u8>,
+}
+
+impl Encoder {
+    ///
+    /// Creates an encoder with a pre-allocated buffer.
+    ///
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity),
+        }
+    }
+
+    ///
+    /// Writes a data item head with the smallest argument width.
+    ///
+    pub fn write_head(&mut self, major_type: MajorType, argument: u64) {
+        self.write_head_with_width(major_type, argument, ArgumentWidth::Immediate);
+    }
+
+    ///
+    /// Writes a data item head with the argument width of at least `min_width`.
+    ///
+    pub fn write_head_with_width(
+        &mut self,
+        major_type: MajorType,
+        argument: u64,
+        min_width: ArgumentWidth,
+    ) {
+        let width = ArgumentWidth::minimal(argument).max(min_width);
+        self.buffer.push(
+            ((major_type as u8) << MajorType::SHIFT) | width.additional_information(argument),
+        );
+        let argument_bytes = argument.to_be_bytes();
+        self.buffer
+            .extend_from_slice(&argument_bytes[argument_bytes.len() - width.byte_length()..]);
+    }
+
+    ///
+    /// Writes a map head with `length` key-value pairs.
+    ///
+    pub fn write_map_head(&mut self, length: usize) {
+        self.write_head(MajorType::Map, length as u64);
+    }
+
+    ///
+    /// Writes a byte string.
+    ///
+    pub fn write_byte_string(&mut self, value: &[u8]) {
+        self.write_head(MajorType::ByteString, value.len() as u64);
+        self.buffer.extend_from_slice(value);
+    }
+
+    ///
+    /// Writes a UTF-8 text string.
+    ///
+    pub fn write_text_string(&mut self, value: &str) {
+        self.write_text_string_with_width(value, ArgumentWidth::Immediate);
+    }
+
+    ///
+    /// Writes a UTF-8 text string with the length width of at least `min_width`.
+    ///
+    pub fn write_text_string_with_width(&mut self, value: &str, min_width: ArgumentWidth) {
+        self.write_head_with_width(MajorType::TextString, value.len() as u64, min_width);
+        self.buffer.extend_from_slice(value.as_bytes());
+    }
+
+    ///
+    /// Writes a boolean simple value.
+    ///
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_head(
+            MajorType::SimpleOrFloat,
+            if value {
+                MajorType::SIMPLE_TRUE
+            } else {
+                MajorType::SIMPLE_FALSE
+            } as u64,
+        );
+    }
+
+    ///
+    /// Writes an unsigned integer.
+    ///
+    pub fn write_unsigned(&mut self, value: u64) {
+        self.write_head(MajorType::UnsignedInteger, value);
+    }
+
+    ///
+    /// Writes a metadata field value.
+    ///
+    pub fn write_value(&mut self, value: &Value) {
+        match value {
+            Value::Text(value) => self.write_text_string(value.as_str()),
+            Value::Bytes(value) => self.write_byte_string(value.as_slice()),
+            Value::Bool(value) => self.write_bool(*value),
+            Value::Unsigned(value) => self.write_unsigned(*value),
+        }
+    }
+
+    ///
+    /// Writes already encoded data.
+    ///
+    pub fn write_raw(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    ///
+    /// Extracts the encoded data.
+    ///
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cbor::argument_width::ArgumentWidth;
+    use crate::cbor::major_type::MajorType;
+
+    fn head(argument: u64) -> String {
+        let mut encoder = super::Encoder::default();
+        encoder.write_head(MajorType::UnsignedInteger, argument);
+        hex::encode(encoder.into_inner())
+    }
+
+    #[test]
+    fn immediate() {
+        assert_eq!(head(0), "00");
+        assert_eq!(head(23), "17");
+    }
+
+    #[test]
+    fn u8() {
+        assert_eq!(head(24), "1818");
+        assert_eq!(head(255), "18ff");
+    }
+
+    #[test]
+    fn u16() {
+        assert_eq!(head(256), "190100");
+        assert_eq!(head(65535), "19ffff");
+    }
+
+    #[test]
+    fn u32() {
+        assert_eq!(head(65536), "1a00010000");
+        assert_eq!(head(u32::MAX as u64), "1affffffff");
+    }
+
+    #[test]
+    fn u64() {
+        assert_eq!(head(u32::MAX as u64 + 1), "1b0000000100000000");
+        assert_eq!(head(u64::MAX), "1bffffffffffffffff");
+    }
+
+    #[test]
+    fn text_string_with_width() {
+        let mut encoder = super::Encoder::default();
+        encoder.write_text_string_with_width("solc", ArgumentWidth::U8);
+        assert_eq!(hex::encode(encoder.into_inner()), "7804736f6c63");
+    }
+
+    #[test]
+    fn bool() {
+        let mut encoder = super::Encoder::default();
+        encoder.write_bool(true);
+        encoder.write_bool(false);
+        assert_eq!(hex::encode(encoder.into_inner()), "f5f4");
+    }
+
+    #[test]
+    fn byte_string_long() {
+        let mut encoder = super::Encoder::default();
+        encoder.write_byte_string(vec![0xAB_u8; 300].as_slice());
+        let encoded = encoder.into_inner();
+        assert_eq!(hex::encode(&encoded[..3]), "59012c");
+        assert_eq!(encoded.len(), 303);
+    }
+}
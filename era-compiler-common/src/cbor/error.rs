@@ -0,0 +1,65 @@
+//!
+//! CBOR metadata error.
+//!
+
+///
+/// CBOR metadata error.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The version data is empty.
+    EmptyVersionData,
+    /// The solc layout allows exactly one version data entry.
+    SolcVersionDataCount {
+        /// The number of version data entries found.
+        found: usize,
+    },
+    /// The solc version cannot be encoded in 3 bytes.
+    SolcVersionOutOfRange {
+        /// The offending version.
+        version: semver::Version,
+    },
+    /// The key or version data name is not allowed.
+    InvalidKey {
+        /// The offending key.
+        key: String,
+        /// The reason the key is rejected.
+        reason: &'static str,
+    },
+    /// The key is used more than once.
+    DuplicateKey {
+        /// The offending key.
+        key: String,
+    },
+    /// The encoded payload length does not fit into its 2-byte suffix.
+    PayloadTooLong {
+        /// The encoded payload length.
+        length: usize,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyVersionData => write!(f, "Version data cannot be empty"),
+            Self::SolcVersionDataCount { found } => write!(
+                f,
+                "The solc layout requires exactly one version data entry, found {found}"
+            ),
+            Self::SolcVersionOutOfRange { version } => write!(
+                f,
+                "The solc version `{version}` components must not exceed {}",
+                u8::MAX
+            ),
+            Self::InvalidKey { key, reason } => write!(f, "Invalid key `{key}`: {reason}"),
+            Self::DuplicateKey { key } => write!(f, "Duplicate key `{key}`"),
+            Self::PayloadTooLong { length } => write!(
+                f,
+                "The CBOR payload length {length} exceeds the maximum of {}",
+                u16::MAX
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
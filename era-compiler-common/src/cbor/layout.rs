@@ -0,0 +1,30 @@
+//!
+//! CBOR metadata layout.
+//!
+
+///
+/// CBOR metadata layout.
+///
+/// Defines how the version field is encoded.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// The ZKsync layout.
+    ///
+    /// All version data entries are encoded as a text string of `name:version` pairs separated by `;`.
+    #[default]
+    ZKsync,
+    /// The solc layout, which is byte-for-byte identical to the metadata emitted by solc.
+    ///
+    /// Only the first version data entry is encoded: as 3 raw bytes for releases,
+    /// and as a full version text string for prereleases.
+    Solc {
+        /// Whether the `experimental: true` field is added.
+        experimental: bool,
+    },
+}
+
+impl Layout {
+    /// The key of the solc experimental mode field.
+    pub const SOLC_EXPERIMENTAL_KEY: &'static str = "experimental";
+}
@@ -0,0 +1,72 @@
+//!
+//! CBOR major type.
+//!
+
+///
+/// CBOR major type.
+///
+/// Stored in the three most significant bits of the initial byte of each data item.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MajorType {
+    /// Unsigned integer.
+    UnsignedInteger = 0,
+    /// Negative integer.
+    NegativeInteger = 1,
+    /// Byte string.
+    ByteString = 2,
+    /// UTF-8 text string.
+    TextString = 3,
+    /// Array of data items.
+    Array = 4,
+    /// Map of pairs of data items.
+    Map = 5,
+    /// Tagged data item.
+    Tag = 6,
+    /// Floating-point number or simple value.
+    SimpleOrFloat = 7,
+}
+
+impl MajorType {
+    /// The number of bits the major type is shifted by in the initial byte.
+    pub const SHIFT: u8 = 5;
+
+    /// The mask of the additional information in the initial byte.
+    pub const ADDITIONAL_INFORMATION_MASK: u8 = 0b0001_1111;
+
+    /// The `false` simple value.
+    pub const SIMPLE_FALSE: u8 = 20;
+
+    /// The `true` simple value.
+    pub const SIMPLE_TRUE: u8 = 21;
+}
+
+impl From<u8> for MajorType {
+    fn from(initial_byte: u8) -> Self {
+        match initial_byte >> Self::SHIFT {
+            0 => Self::UnsignedInteger,
+            1 => Self::NegativeInteger,
+            2 => Self::ByteString,
+            3 => Self::TextString,
+            4 => Self::Array,
+            5 => Self::Map,
+            6 => Self::Tag,
+            _ => Self::SimpleOrFloat,
+        }
+    }
+}
+
+impl std::fmt::Display for MajorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsignedInteger => write!(f, "unsigned integer"),
+            Self::NegativeInteger => write!(f, "negative integer"),
+            Self::ByteString => write!(f, "byte string"),
+            Self::TextString => write!(f, "text string"),
+            Self::Array => write!(f, "array"),
+            Self::Map => write!(f, "map"),
+            Self::Tag => write!(f, "tag"),
+            Self::SimpleOrFloat => write!(f, "simple value or float"),
+        }
+    }
+}
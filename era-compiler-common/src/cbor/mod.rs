@@ -0,0 +1,933 @@
+//!
+//! CBOR utilities.
+//!
+
+pub mod argument_width;
+pub mod decoder;
+pub mod encoder;
+pub mod error;
+pub mod layout;
+pub mod major_type;
+pub mod value;
+
+use self::argument_width::ArgumentWidth;
+use self::decoder::Decoder;
+use self::encoder::Encoder;
+use self::error::Error;
+use self::layout::Layout;
+use self::major_type::MajorType;
+use self::value::Value;
+
+///
+/// CBOR payload.
+///
+/// Used for encoding IPFS contract metadata hash.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct CBOR<'a, S>
+where
+    S: ToString,
+{
+    /// Hash type name and hash itself in binary representation.
+    pub hash: Option<(S, &'a [u8])>,
+    /// Key of the version field.
+    pub version_key: String,
+    /// Version data to be encoded in the `version_key` field.
+    pub version_data: Vec<(String, semver::Version)>,
+    /// Layout of the version field.
+    pub layout: Layout,
+    /// Extra fields encoded after the hash and version fields, sorted by encoded key.
+    pub extra_fields: Vec<(String, Value)>,
+}
+
+impl<'a, S> CBOR<'a, S>
+where
+    S: ToString,
+{
+    /// The key of the version field in the solc layout.
+    pub const SOLC_VERSION_KEY: &'static str = "solc";
+
+    /// The hash type names known to solc.
+    pub const SOLC_HASH_KEYS: [&'static str; 3] = ["ipfs", "bzzr0", "bzzr1"];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// # Panics
+    /// If the arguments are invalid. See `try_new` for the fallible version.
+    ///
+    pub fn new(
+        hash: Option<(S, &'a [u8])>,
+        version_key: String,
+        version_data: Vec<(String, semver::Version)>,
+    ) -> Self {
+        Self::try_new(hash, version_key, version_data).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    ///
+    /// A fallible constructor.
+    ///
+    pub fn try_new(
+        hash: Option<(S, &'a [u8])>,
+        version_key: String,
+        version_data: Vec<(String, semver::Version)>,
+    ) -> Result<Self, Error> {
+        let cbor = Self {
+            hash,
+            version_key,
+            version_data,
+            layout: Layout::ZKsync,
+            extra_fields: vec![],
+        };
+        cbor.validate()?;
+        Ok(cbor)
+    }
+
+    ///
+    /// A shortcut constructor for the solc layout.
+    ///
+    /// # Panics
+    /// If the arguments are invalid. See `try_new_solc` for the fallible version.
+    ///
+    pub fn new_solc(
+        hash: Option<(S, &'a [u8])>,
+        solc_version: semver::Version,
+        experimental: bool,
+    ) -> Self {
+        Self::try_new_solc(hash, solc_version, experimental)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    ///
+    /// A fallible constructor for the solc layout.
+    ///
+    /// The hash type name must be one of those known to solc: `ipfs`, `bzzr0`, or `bzzr1`.
+    ///
+    pub fn try_new_solc(
+        hash: Option<(S, &'a [u8])>,
+        solc_version: semver::Version,
+        experimental: bool,
+    ) -> Result<Self, Error> {
+        let cbor = Self {
+            hash,
+            version_key: Self::SOLC_VERSION_KEY.to_owned(),
+            version_data: vec![(Self::SOLC_VERSION_KEY.to_owned(), solc_version)],
+            layout: Layout::Solc { experimental },
+            extra_fields: vec![],
+        };
+        cbor.validate()?;
+        Ok(cbor)
+    }
+
+    ///
+    /// Adds an extra field, replacing the existing one with the same key.
+    ///
+    pub fn with_extra_field(mut self, key: String, value: Value) -> Self {
+        match self
+            .extra_fields
+            .iter_mut()
+            .find(|(existing_key, _)| existing_key == &key)
+        {
+            Some((_, existing_value)) => *existing_value = value,
+            None => self.extra_fields.push((key, value)),
+        }
+        self
+    }
+
+    ///
+    /// Returns a CBOR-encoded vector.
+    ///
+    /// # Panics
+    /// If the payload is invalid. See `try_to_vec` for the fallible version.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.try_to_vec().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    ///
+    /// Returns a CBOR-encoded vector, or an error if the payload cannot be represented.
+    ///
+    pub fn try_to_vec(&self) -> Result<Vec<u8>, Error> {
+        self.validate()?;
+
+        let experimental = matches!(self.layout, Layout::Solc { experimental: true });
+        let field_count =
+            (self.hash.is_some() as usize) + (experimental as usize) + 1 + self.extra_fields.len();
+        let mut encoder = Encoder::with_capacity(64);
+        encoder.write_map_head(field_count);
+
+        if let Some((r#type, hash)) = self.hash.as_ref() {
+            encoder.write_text_string(r#type.to_string().as_str());
+            encoder.write_byte_string(hash);
+        }
+
+        match self.layout {
+            Layout::ZKsync => {
+                encoder.write_text_string(self.version_key.as_str());
+                let version_data = self
+                    .version_data
+                    .iter()
+                    .map(|(name, version)| format!("{name}:{version}"))
+                    .collect::<Vec<String>>()
+                    .join(";");
+                // The version data length has always been encoded with at least one byte,
+                // which is kept to preserve the reproducibility of existing bytecode.
+                encoder.write_text_string_with_width(version_data.as_str(), ArgumentWidth::U8);
+            }
+            Layout::Solc { experimental } => {
+                if experimental {
+                    encoder.write_text_string(Layout::SOLC_EXPERIMENTAL_KEY);
+                    encoder.write_bool(true);
+                }
+
+                let (_name, version) = self.version_data.first().expect("Always exists");
+                encoder.write_text_string(self.version_key.as_str());
+                if version.pre.is_empty() {
+                    encoder.write_byte_string(&[
+                        version.major as u8,
+                        version.minor as u8,
+                        version.patch as u8,
+                    ]);
+                } else {
+                    // `solc` omits the build metadata from the version string.
+                    let version = format!(
+                        "{}.{}.{}-{}",
+                        version.major, version.minor, version.patch, version.pre
+                    );
+                    encoder.write_text_string(version.as_str());
+                }
+            }
+        }
+
+        // The hash and version fields keep their fixed positions to preserve the reproducibility
+        // of existing bytecode, so only the extra fields following them are sorted by encoded key.
+        let mut extra_fields = self
+            .extra_fields
+            .iter()
+            .map(|(key, value)| {
+                let mut key_encoder = Encoder::default();
+                key_encoder.write_text_string(key.as_str());
+                (key_encoder.into_inner(), value)
+            })
+            .collect::<Vec<(Vec<u8>, &Value)>>();
+        extra_fields.sort_by(|(key_1, _), (key_2, _)| key_1.cmp(key_2));
+        for (key, value) in extra_fields.into_iter() {
+            encoder.write_raw(key.as_slice());
+            encoder.write_value(value);
+        }
+
+        let mut cbor = encoder.into_inner();
+        let length =
+            u16::try_from(cbor.len()).map_err(|_| Error::PayloadTooLong { length: cbor.len() })?;
+        cbor.extend(length.to_be_bytes());
+        Ok(cbor)
+    }
+
+    ///
+    /// Checks that the payload can be encoded and decoded back unambiguously.
+    ///
+    fn validate(&self) -> Result<(), Error> {
+        if self.version_data.is_empty() {
+            return Err(Error::EmptyVersionData);
+        }
+
+        let mut keys = Vec::with_capacity(3 + self.extra_fields.len());
+        if let Some((r#type, _)) = self.hash.as_ref() {
+            keys.push(r#type.to_string());
+        }
+        keys.push(self.version_key.clone());
+
+        match self.layout {
+            Layout::ZKsync => {
+                for (name, _) in self.version_data.iter() {
+                    if name.is_empty() {
+                        return Err(Error::InvalidKey {
+                            key: name.to_owned(),
+                            reason: "version data names cannot be empty",
+                        });
+                    }
+                    if name.contains([':', ';']) {
+                        return Err(Error::InvalidKey {
+                            key: name.to_owned(),
+                            reason: "version data names cannot contain `:` or `;`",
+                        });
+                    }
+                }
+            }
+            Layout::Solc { experimental } => {
+                if self.version_data.len() != 1 {
+                    return Err(Error::SolcVersionDataCount {
+                        found: self.version_data.len(),
+                    });
+                }
+                let (_name, version) = &self.version_data[0];
+                if version.pre.is_empty()
+                    && [version.major, version.minor, version.patch]
+                        .into_iter()
+                        .any(|component| component > u8::MAX as u64)
+                {
+                    return Err(Error::SolcVersionOutOfRange {
+                        version: version.to_owned(),
+                    });
+                }
+                if let Some((r#type, _)) = self.hash.as_ref() {
+                    let r#type = r#type.to_string();
+                    if !Self::SOLC_HASH_KEYS.contains(&r#type.as_str()) {
+                        return Err(Error::InvalidKey {
+                            key: r#type,
+                            reason: "solc only supports `ipfs`, `bzzr0`, and `bzzr1` hashes",
+                        });
+                    }
+                }
+                if experimental {
+                    keys.push(Layout::SOLC_EXPERIMENTAL_KEY.to_owned());
+                }
+            }
+        }
+        keys.extend(self.extra_fields.iter().map(|(key, _)| key.to_owned()));
+
+        for (index, key) in keys.iter().enumerate() {
+            if key.is_empty() {
+                return Err(Error::InvalidKey {
+                    key: key.to_owned(),
+                    reason: "keys cannot be empty",
+                });
+            }
+            if keys[..index].contains(key) {
+                return Err(Error::DuplicateKey {
+                    key: key.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> CBOR<'a, String> {
+    ///
+    /// Decodes the CBOR payload from the end of `bytecode`.
+    ///
+    pub fn from_bytecode(bytecode: &'a [u8]) -> anyhow::Result<Self> {
+        Self::from_slice(Self::trailer(bytecode)?)
+    }
+
+    ///
+    /// Returns the CBOR payload at the end of `bytecode` without decoding it.
+    ///
+    /// The payload is located using the 2-byte big-endian length that terminates it.
+    ///
+    pub fn trailer(bytecode: &'a [u8]) -> anyhow::Result<&'a [u8]> {
+        let length = Self::trailer_length(bytecode)?;
+        if length > bytecode.len() {
+            anyhow::bail!(
+                "CBOR payload length {} exceeds the bytecode size {}",
+                length - 2,
+                bytecode.len()
+            );
+        }
+        Ok(&bytecode[bytecode.len() - length..])
+    }
+
+    ///
+    /// Decodes the CBOR payload from `slice`, as it is returned by `to_vec`.
+    ///
+    /// The slice must contain the CBOR map followed by its 2-byte big-endian length.
+    /// Both the ZKsync and solc layouts are recognized. The fields following the version field
+    /// are returned as extra fields.
+    ///
+    pub fn from_slice(slice: &'a [u8]) -> anyhow::Result<Self> {
+        let length = Self::trailer_length(slice)?;
+        if length != slice.len() {
+            anyhow::bail!(
+                "CBOR payload length {} does not match the actual length {}",
+                length - 2,
+                slice.len() - 2
+            );
+        }
+
+        let mut decoder = Decoder::new(&slice[..slice.len() - 2]);
+        let field_count = decoder.read_map_length()?;
+
+        let mut hash = None;
+        let mut experimental = false;
+        let mut version = None;
+        let mut extra_fields = Vec::new();
+        for index in 0..field_count {
+            let key = decoder.read_text_string()?;
+            if version.is_some() {
+                extra_fields.push((key.to_owned(), decoder.read_value()?));
+                continue;
+            }
+
+            match decoder.peek_major_type()? {
+                MajorType::ByteString if key == Self::SOLC_VERSION_KEY => {
+                    let version_bytes = decoder.read_byte_string()?;
+                    let [major, minor, patch] = version_bytes else {
+                        anyhow::bail!(
+                            "Invalid solc version `0x{}`: expected 3 bytes",
+                            hex::encode(version_bytes)
+                        );
+                    };
+                    let solc_version =
+                        semver::Version::new(*major as u64, *minor as u64, *patch as u64);
+                    version = Some((key.to_owned(), vec![(key.to_owned(), solc_version)], true));
+                }
+                MajorType::ByteString if index == 0 => {
+                    hash = Some((key.to_owned(), decoder.read_byte_string()?));
+                }
+                MajorType::TextString => {
+                    let data = decoder.read_text_string()?;
+                    version = Some(match semver::Version::parse(data) {
+                        Ok(solc_version) => {
+                            (key.to_owned(), vec![(key.to_owned(), solc_version)], true)
+                        }
+                        Err(_) => (key.to_owned(), Self::parse_version_data(data)?, false),
+                    });
+                }
+                MajorType::SimpleOrFloat
+                    if key == Layout::SOLC_EXPERIMENTAL_KEY && !experimental =>
+                {
+                    experimental = decoder.read_bool()?;
+                }
+                major_type => anyhow::bail!("Unexpected CBOR {major_type} in field `{key}`"),
+            }
+        }
+        if !decoder.is_finished() {
+            anyhow::bail!("Unexpected data after the CBOR map");
+        }
+
+        let (version_key, version_data, is_solc_layout) =
+            version.ok_or_else(|| anyhow::anyhow!("CBOR version field is missing"))?;
+        let layout = match (is_solc_layout, experimental) {
+            (true, experimental) => Layout::Solc { experimental },
+            (false, false) => Layout::ZKsync,
+            (false, true) => anyhow::bail!(
+                "CBOR field `{}` is only allowed before the version field in the solc layout",
+                Layout::SOLC_EXPERIMENTAL_KEY
+            ),
+        };
+        Ok(Self {
+            hash,
+            version_key,
+            version_data,
+            layout,
+            extra_fields,
+        })
+    }
+
+    ///
+    /// Returns the length of the CBOR payload at the end of `data`, including the 2-byte length itself.
+    ///
+    fn trailer_length(data: &[u8]) -> anyhow::Result<usize> {
+        let length_bytes = data
+            .len()
+            .checked_sub(2)
+            .map(|offset| &data[offset..])
+            .ok_or_else(|| anyhow::anyhow!("CBOR payload length is missing"))?;
+        Ok(u16::from_be_bytes([length_bytes[0], length_bytes[1]]) as usize + 2)
+    }
+
+    ///
+    /// Parses the `name:version` pairs separated by `;`.
+    ///
+    fn parse_version_data(data: &str) -> anyhow::Result<Vec<(String, semver::Version)>> {
+        data.split(';')
+            .map(|pair| {
+                let (name, version) = pair.split_once(':').ok_or_else(|| {
+                    anyhow::anyhow!("Invalid version data entry `{pair}`: expected `name:version`")
+                })?;
+                let version = semver::Version::parse(version).map_err(|error| {
+                    anyhow::anyhow!("Invalid version `{version}` of `{name}`: {error}")
+                })?;
+                Ok((name.to_owned(), version))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IPFSHash;
+    use crate::Keccak256Hash;
+    use crate::MetadataHashType;
+
+    #[test]
+    #[should_panic(expected = "Version data cannot be empty")]
+    fn none() {
+        let cbor = super::CBOR::<'_, String>::new(None, "solc".to_owned(), vec![]);
+
+        assert_eq!(hex::encode(cbor.to_vec()), "a164736f6c63600007");
+    }
+
+    #[test]
+    fn none_and_zksolc_version_data() {
+        let cbor = super::CBOR::<'_, String>::new(
+            None,
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a164736f6c63780d7a6b736f6c633a302e382e32390015"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Version data cannot be empty")]
+    fn ipfs_hash_only() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            vec![],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c63600030",
+        );
+    }
+
+    #[test]
+    fn ipfs_hash_and_zksolc_version_data() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c63780d7a6b736f6c633a302e382e3239003e"
+        );
+    }
+
+    #[test]
+    fn ipfs_hash_and_zkvyper_version_data() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            "vyper".to_owned(),
+            vec![
+                ("zkvyper".to_owned(), semver::Version::new(1, 5, 10)),
+                ("vyper".to_owned(), semver::Version::new(0, 4, 1)),
+            ],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125657679706572781a7a6b76797065723a312e352e31303b76797065723a302e342e31004c"
+        );
+    }
+
+    #[test]
+    fn ipfs_hash_and_extended_version_data() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            vec![
+                ("zksolc".to_string(), semver::Version::new(0, 1, 0)),
+                ("solc".to_string(), semver::Version::new(0, 8, 29)),
+                ("llvm".to_string(), semver::Version::new(1, 0, 2)),
+            ],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c6378237a6b736f6c633a302e312e303b736f6c633a302e382e32393b6c6c766d3a312e302e320054"
+        );
+    }
+
+    #[test]
+    fn decode_none_and_zksolc_version_data() {
+        let cbor = super::CBOR::<'_, String>::new(
+            None,
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        );
+        let encoded = cbor.to_vec();
+
+        assert_eq!(
+            super::CBOR::from_slice(encoded.as_slice()).expect("Always valid"),
+            cbor
+        );
+    }
+
+    #[test]
+    fn decode_ipfs_hash_and_extended_version_data() {
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((MetadataHashType::IPFS.to_string(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            vec![
+                ("zksolc".to_string(), semver::Version::new(0, 1, 0)),
+                ("solc".to_string(), semver::Version::new(0, 8, 29)),
+                ("llvm".to_string(), semver::Version::new(1, 0, 2)),
+            ],
+        );
+        let encoded = cbor.to_vec();
+
+        assert_eq!(
+            super::CBOR::from_slice(encoded.as_slice()).expect("Always valid"),
+            cbor
+        );
+    }
+
+    #[test]
+    fn decode_from_bytecode() {
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((MetadataHashType::IPFS.to_string(), ipfs.as_bytes())),
+            "vyper".to_owned(),
+            vec![
+                ("zkvyper".to_owned(), semver::Version::new(1, 5, 10)),
+                ("vyper".to_owned(), semver::Version::new(0, 4, 1)),
+            ],
+        );
+        let mut bytecode = vec![0xAA_u8; 64];
+        bytecode.extend(cbor.to_vec());
+
+        assert_eq!(
+            super::CBOR::from_bytecode(bytecode.as_slice()).expect("Always valid"),
+            cbor
+        );
+    }
+
+    #[test]
+    fn decode_error_length_mismatch() {
+        let encoded =
+            hex::decode("a164736f6c63780d7a6b736f6c633a302e382e32390016").expect("Always valid");
+
+        assert!(super::CBOR::from_slice(encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    fn decode_error_truncated_bytecode() {
+        let encoded = hex::decode("7a6b736f6c633a302e382e32390015").expect("Always valid");
+
+        assert!(super::CBOR::from_bytecode(encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    fn decode_error_invalid_version_data() {
+        let encoded =
+            hex::decode("a164736f6c63780d7a6b736f6c633a302e382e32790015").expect("Always valid");
+
+        assert!(super::CBOR::from_slice(encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    fn keccak256_hash_and_zksolc_version_data() {
+        let hash_type = MetadataHashType::Keccak256.to_string();
+        let keccak256 = Keccak256Hash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), keccak256.as_bytes())),
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        );
+        let encoded = cbor.to_vec();
+
+        assert_eq!(hex::encode(&encoded[..12]), "a2696b656363616b32353658");
+        assert_eq!(
+            super::CBOR::from_slice(encoded.as_slice())
+                .expect("Always valid")
+                .hash,
+            Some((hash_type, keccak256.as_bytes()))
+        );
+    }
+
+    #[test]
+    fn solc_ipfs_hash_and_release_version() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new_solc(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            semver::Version::new(0, 8, 19),
+            false,
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c63430008130033"
+        );
+    }
+
+    #[test]
+    fn solc_ipfs_hash_and_experimental() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new_solc(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            semver::Version::new(0, 8, 19),
+            true,
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a364697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b51256c6578706572696d656e74616cf564736f6c63430008130041"
+        );
+    }
+
+    #[test]
+    fn solc_bzzr1_hash_and_release_version() {
+        let hash = [0x11_u8; crate::BYTE_LENGTH_FIELD];
+
+        let cbor = super::CBOR::new_solc(
+            Some(("bzzr1", hash.as_slice())),
+            semver::Version::new(0, 5, 16),
+            false,
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a265627a7a72315820111111111111111111111111111111111111111111111111111111111111111164736f6c63430005100032"
+        );
+    }
+
+    #[test]
+    fn solc_none_and_prerelease_version() {
+        let version = semver::Version::parse("0.8.30-nightly.2025.1.1+commit.abcdef12")
+            .expect("Always valid");
+
+        let cbor = super::CBOR::<'_, String>::new_solc(None, version, false);
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a164736f6c6377302e382e33302d6e696768746c792e323032352e312e31001e"
+        );
+    }
+
+    #[test]
+    fn decode_solc_layout() {
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+        let release = super::CBOR::new_solc(
+            Some((MetadataHashType::IPFS.to_string(), ipfs.as_bytes())),
+            semver::Version::new(0, 8, 19),
+            true,
+        );
+        let prerelease = super::CBOR::new_solc(
+            Some((MetadataHashType::IPFS.to_string(), ipfs.as_bytes())),
+            semver::Version::parse("0.8.30-nightly.2025.1.1").expect("Always valid"),
+            false,
+        );
+
+        for cbor in [release, prerelease].into_iter() {
+            let encoded = cbor.to_vec();
+            assert_eq!(
+                super::CBOR::from_slice(encoded.as_slice()).expect("Always valid"),
+                cbor
+            );
+        }
+    }
+
+    #[test]
+    fn extra_fields_sorted_after_fixed_fields() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        )
+        .with_extra_field("optimizer".to_owned(), "3".into())
+        .with_extra_field("experimental".to_owned(), true.into())
+        .with_extra_field("llvm".to_owned(), vec![0xAB_u8, 0xCD].into())
+        .with_extra_field("size".to_owned(), 1000_u64.into());
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a664697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c63780d7a6b736f6c633a302e382e3239646c6c766d42abcd6473697a651903e8696f7074696d697a657261336c6578706572696d656e74616cf50068"
+        );
+    }
+
+    #[test]
+    fn extra_field_replaced() {
+        let cbor = super::CBOR::<'_, String>::new(
+            None,
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        )
+        .with_extra_field("optimizer".to_owned(), "3".into())
+        .with_extra_field("optimizer".to_owned(), "z".into());
+
+        assert_eq!(
+            cbor.extra_fields,
+            vec![("optimizer".to_owned(), super::Value::Text("z".to_owned()))]
+        );
+    }
+
+    #[test]
+    fn decode_extra_fields() {
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((MetadataHashType::IPFS.to_string(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        )
+        .with_extra_field("experimental".to_owned(), true.into())
+        .with_extra_field("llvm".to_owned(), vec![0xAB_u8, 0xCD].into())
+        .with_extra_field("optimizer".to_owned(), "3".into())
+        .with_extra_field("size".to_owned(), 1000_u64.into());
+        let encoded = cbor.to_vec();
+
+        let mut decoded = super::CBOR::from_slice(encoded.as_slice()).expect("Always valid");
+        decoded
+            .extra_fields
+            .sort_by(|(key_1, _), (key_2, _)| key_1.cmp(key_2));
+        assert_eq!(decoded, cbor);
+    }
+
+    #[test]
+    fn error_empty_version_data() {
+        assert_eq!(
+            super::CBOR::<'_, String>::try_new(None, "solc".to_owned(), vec![]),
+            Err(super::Error::EmptyVersionData)
+        );
+    }
+
+    #[test]
+    fn error_invalid_version_data_name() {
+        assert_eq!(
+            super::CBOR::<'_, String>::try_new(
+                None,
+                "solc".to_owned(),
+                vec![("zk:solc".to_owned(), semver::Version::new(0, 8, 29))],
+            ),
+            Err(super::Error::InvalidKey {
+                key: "zk:solc".to_owned(),
+                reason: "version data names cannot contain `:` or `;`",
+            })
+        );
+    }
+
+    #[test]
+    fn error_empty_version_key() {
+        assert!(matches!(
+            super::CBOR::<'_, String>::try_new(
+                None,
+                "".to_owned(),
+                vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+            ),
+            Err(super::Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn error_solc_unknown_hash_type() {
+        let hash_type = MetadataHashType::Keccak256.to_string();
+        let keccak256 = Keccak256Hash::from_slice("LLVM is the Endgame".as_bytes());
+
+        assert!(matches!(
+            super::CBOR::try_new_solc(
+                Some((hash_type.as_str(), keccak256.as_bytes())),
+                semver::Version::new(0, 8, 29),
+                false,
+            ),
+            Err(super::Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn error_solc_version_out_of_range() {
+        assert!(matches!(
+            super::CBOR::<'_, String>::try_new_solc(None, semver::Version::new(0, 8, 256), false),
+            Err(super::Error::SolcVersionOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn error_duplicate_extra_field() {
+        let cbor = super::CBOR::<'_, String>::new(
+            None,
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        )
+        .with_extra_field("solc".to_owned(), true.into());
+
+        assert_eq!(
+            cbor.try_to_vec(),
+            Err(super::Error::DuplicateKey {
+                key: "solc".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn error_payload_too_long() {
+        let hash = vec![0_u8; u16::MAX as usize];
+        let cbor = super::CBOR::new(
+            Some(("ipfs", hash.as_slice())),
+            "solc".to_owned(),
+            vec![("zksolc".to_owned(), semver::Version::new(0, 8, 29))],
+        );
+
+        assert!(matches!(
+            cbor.try_to_vec(),
+            Err(super::Error::PayloadTooLong { .. })
+        ));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn decodable_with_arbitrary_lengths(
+            hash_type in "\\PC{1,40}",
+            hash in proptest::collection::vec(proptest::num::u8::ANY, 0..600),
+            version_key in "\\PC{1,100}",
+            version_data in proptest::collection::vec(
+                ("[a-zA-Z0-9_-]{1,40}", 0..1000_u64, 0..1000_u64, 0..1000_u64),
+                1..32,
+            ),
+        ) {
+            proptest::prop_assume!(hash_type != version_key);
+
+            let version_data = version_data
+                .into_iter()
+                .map(|(name, major, minor, patch)| (name, semver::Version::new(major, minor, patch)))
+                .collect::<Vec<(String, semver::Version)>>();
+            let cbor = super::CBOR::new(
+                Some((hash_type.clone(), hash.as_slice())),
+                version_key.clone(),
+                version_data.clone(),
+            );
+            let encoded = cbor.to_vec();
+
+            let (map, length) = encoded.split_at(encoded.len() - 2);
+            proptest::prop_assert_eq!(u16::from_be_bytes([length[0], length[1]]) as usize, map.len());
+
+            let value: ciborium::Value = ciborium::from_reader(map).expect("Always valid");
+            let joined_version_data = version_data
+                .iter()
+                .map(|(name, version)| format!("{name}:{version}"))
+                .collect::<Vec<String>>()
+                .join(";");
+            proptest::prop_assert_eq!(
+                value,
+                ciborium::Value::Map(vec![
+                    (ciborium::Value::Text(hash_type), ciborium::Value::Bytes(hash.clone())),
+                    (ciborium::Value::Text(version_key), ciborium::Value::Text(joined_version_data)),
+                ])
+            );
+
+            proptest::prop_assert_eq!(super::CBOR::from_slice(encoded.as_slice()).expect("Always valid"), cbor);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//!
+//! CBOR metadata field value.
+//!
+
+///
+/// CBOR metadata field value.
+///
+/// Used for the extra fields of the metadata map.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// UTF-8 text string.
+    Text(String),
+    /// Byte string.
+    Bytes(Vec<u8>),
+    /// Boolean.
+    Bool(bool),
+    /// Unsigned integer.
+    Unsigned(u64),
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Self::Unsigned(value)
+    }
+}
@@ -0,0 +1,146 @@
+//!
+//! EVM feature.
+//!
+
+use crate::evm_version::EVMVersion;
+
+///
+/// EVM feature.
+///
+/// Each feature is introduced by an EIP that landed in a specific hardfork.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EVMFeature {
+    /// The `REVERT` instruction.
+    Revert,
+    /// The `RETURNDATASIZE` and `RETURNDATACOPY` instructions.
+    ReturnDataCopy,
+    /// The `STATICCALL` instruction.
+    StaticCall,
+    /// The `SHL`, `SHR` and `SAR` instructions.
+    BitwiseShifts,
+    /// The `CREATE2` instruction.
+    Create2,
+    /// The `EXTCODEHASH` instruction.
+    ExtCodeHash,
+    /// The `CHAINID` instruction.
+    ChainId,
+    /// The `SELFBALANCE` instruction.
+    SelfBalance,
+    /// The cold and warm access costs of accounts and storage slots.
+    AccessLists,
+    /// The `BASEFEE` instruction.
+    BaseFee,
+    /// The `PREVRANDAO` instruction replacing `DIFFICULTY`.
+    PrevRandao,
+    /// The `PUSH0` instruction.
+    Push0,
+    /// The `TSTORE` and `TLOAD` instructions.
+    TransientStorage,
+    /// The `MCOPY` instruction.
+    MCopy,
+    /// The `BLOBHASH` instruction.
+    BlobHash,
+    /// The `BLOBBASEFEE` instruction.
+    BlobBaseFee,
+    /// The `SELFDESTRUCT` instruction only deletes contracts created in the same transaction.
+    SelfDestructInSameTransactionOnly,
+    /// The `CLZ` instruction.
+    CountLeadingZeros,
+}
+
+impl EVMFeature {
+    /// All the features, in the order of their introduction.
+    pub const ALL: [Self; 18] = [
+        Self::Revert,
+        Self::ReturnDataCopy,
+        Self::StaticCall,
+        Self::BitwiseShifts,
+        Self::Create2,
+        Self::ExtCodeHash,
+        Self::ChainId,
+        Self::SelfBalance,
+        Self::AccessLists,
+        Self::BaseFee,
+        Self::PrevRandao,
+        Self::Push0,
+        Self::TransientStorage,
+        Self::MCopy,
+        Self::BlobHash,
+        Self::BlobBaseFee,
+        Self::SelfDestructInSameTransactionOnly,
+        Self::CountLeadingZeros,
+    ];
+
+    ///
+    /// Returns the number of the EIP introducing the feature.
+    ///
+    pub const fn eip(&self) -> u16 {
+        match self {
+            Self::Revert => 140,
+            Self::ReturnDataCopy => 211,
+            Self::StaticCall => 214,
+            Self::BitwiseShifts => 145,
+            Self::Create2 => 1014,
+            Self::ExtCodeHash => 1052,
+            Self::ChainId => 1344,
+            Self::SelfBalance => 1884,
+            Self::AccessLists => 2929,
+            Self::BaseFee => 3198,
+            Self::PrevRandao => 4399,
+            Self::Push0 => 3855,
+            Self::TransientStorage => 1153,
+            Self::MCopy => 5656,
+            Self::BlobHash => 4844,
+            Self::BlobBaseFee => 7516,
+            Self::SelfDestructInSameTransactionOnly => 6780,
+            Self::CountLeadingZeros => 7939,
+        }
+    }
+
+    ///
+    /// Returns the EVM version where the feature was introduced.
+    ///
+    pub const fn since(&self) -> EVMVersion {
+        match self {
+            Self::Revert | Self::ReturnDataCopy | Self::StaticCall => EVMVersion::Byzantium,
+            Self::BitwiseShifts | Self::Create2 | Self::ExtCodeHash => EVMVersion::Constantinople,
+            Self::ChainId | Self::SelfBalance => EVMVersion::Istanbul,
+            Self::AccessLists => EVMVersion::Berlin,
+            Self::BaseFee => EVMVersion::London,
+            Self::PrevRandao => EVMVersion::Paris,
+            Self::Push0 => EVMVersion::Shanghai,
+            Self::TransientStorage
+            | Self::MCopy
+            | Self::BlobHash
+            | Self::BlobBaseFee
+            | Self::SelfDestructInSameTransactionOnly => EVMVersion::Cancun,
+            Self::CountLeadingZeros => EVMVersion::Osaka,
+        }
+    }
+}
+
+impl std::fmt::Display for EVMFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Revert => write!(f, "REVERT"),
+            Self::ReturnDataCopy => write!(f, "RETURNDATACOPY"),
+            Self::StaticCall => write!(f, "STATICCALL"),
+            Self::BitwiseShifts => write!(f, "SHL/SHR/SAR"),
+            Self::Create2 => write!(f, "CREATE2"),
+            Self::ExtCodeHash => write!(f, "EXTCODEHASH"),
+            Self::ChainId => write!(f, "CHAINID"),
+            Self::SelfBalance => write!(f, "SELFBALANCE"),
+            Self::AccessLists => write!(f, "access lists"),
+            Self::BaseFee => write!(f, "BASEFEE"),
+            Self::PrevRandao => write!(f, "PREVRANDAO"),
+            Self::Push0 => write!(f, "PUSH0"),
+            Self::TransientStorage => write!(f, "TSTORE/TLOAD"),
+            Self::MCopy => write!(f, "MCOPY"),
+            Self::BlobHash => write!(f, "BLOBHASH"),
+            Self::BlobBaseFee => write!(f, "BLOBBASEFEE"),
+            Self::SelfDestructInSameTransactionOnly => write!(f, "SELFDESTRUCT (EIP-6780)"),
+            Self::CountLeadingZeros => write!(f, "CLZ"),
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//!
+//! EVM gas schedule.
+//!
+
+use crate::evm_version::EVMVersion;
+
+///
+/// EVM gas schedule.
+///
+/// Contains the storage, account access, call, refund, memory, and intrinsic transaction costs
+/// changed by hardforks, as well as the constant ones needed to evaluate them.
+/// The costs of the other instructions are not modeled.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasSchedule {
+    /// The `SLOAD` cost of a cold slot.
+    pub sload_cold: u64,
+    /// The `SLOAD` cost of a warm slot.
+    pub sload_warm: u64,
+    /// The `SSTORE` cost of setting a zero slot to non-zero.
+    pub sstore_set: u64,
+    /// The `SSTORE` cost of changing a non-zero slot, excluding the cold access surcharge.
+    pub sstore_reset: u64,
+    /// The `SSTORE` refund for clearing a slot.
+    pub sstore_clears_refund: u64,
+    /// The `SSTORE` cost of a no-op or an already modified slot, if net gas metering is enabled.
+    pub sstore_dirty: Option<u64>,
+    /// The minimum gas left required by `SSTORE`, if any.
+    pub sstore_sentry: Option<u64>,
+    /// The `TLOAD` cost, if transient storage is supported.
+    pub tload: Option<u64>,
+    /// The `TSTORE` cost, if transient storage is supported.
+    pub tstore: Option<u64>,
+    /// The `BALANCE` cost of a cold account.
+    pub balance_cold: u64,
+    /// The `BALANCE` cost of a warm account.
+    pub balance_warm: u64,
+    /// The `EXTCODESIZE` and `EXTCODECOPY` base cost of a cold account.
+    pub extcode_cold: u64,
+    /// The `EXTCODESIZE` and `EXTCODECOPY` base cost of a warm account.
+    pub extcode_warm: u64,
+    /// The `EXTCODEHASH` cost of a cold account, if the instruction is supported.
+    pub extcodehash_cold: Option<u64>,
+    /// The `EXTCODEHASH` cost of a warm account, if the instruction is supported.
+    pub extcodehash_warm: Option<u64>,
+    /// The `CALL` base cost of a cold account.
+    pub call_cold: u64,
+    /// The `CALL` base cost of a warm account.
+    pub call_warm: u64,
+    /// The `CALL` surcharge for a non-zero value transfer.
+    pub call_value: u64,
+    /// The gas stipend given to the callee of a value transfer.
+    pub call_stipend: u64,
+    /// The `CALL` surcharge for creating a new account.
+    pub call_new_account: u64,
+    /// The `SELFDESTRUCT` base cost, excluding the cold beneficiary surcharge.
+    pub selfdestruct: u64,
+    /// The `SELFDESTRUCT` surcharge for sending the balance to a new account.
+    pub selfdestruct_new_account: u64,
+    /// The `SELFDESTRUCT` refund.
+    pub selfdestruct_refund: u64,
+    /// The divisor of the gas used capping the total refund.
+    pub max_refund_quotient: u64,
+    /// The `EXP` cost per byte of the exponent.
+    pub exp_byte: u64,
+    /// The `MCOPY` base cost, if the instruction is supported.
+    pub mcopy: Option<u64>,
+    /// The cost per word copied by `MCOPY`, `CALLDATACOPY`, and the like.
+    pub copy_word: u64,
+    /// The linear memory expansion cost per word.
+    pub memory_word: u64,
+    /// The quadratic memory expansion cost divisor.
+    pub memory_quadratic_divisor: u64,
+    /// The intrinsic cost of a transaction.
+    pub tx: u64,
+    /// The intrinsic cost of a contract creation transaction.
+    pub tx_create: u64,
+    /// The intrinsic cost of a zero calldata byte.
+    pub tx_data_zero: u64,
+    /// The intrinsic cost of a non-zero calldata byte.
+    pub tx_data_non_zero: u64,
+    /// The intrinsic cost of an init code word, if charged.
+    pub tx_init_code_word: Option<u64>,
+    /// The intrinsic cost of an access list address, if access lists are supported.
+    pub tx_access_list_address: Option<u64>,
+    /// The intrinsic cost of an access list storage key, if access lists are supported.
+    pub tx_access_list_storage_key: Option<u64>,
+    /// The calldata floor cost per token, if charged.
+    pub tx_calldata_floor_per_token: Option<u64>,
+    /// The transaction gas limit cap, if any.
+    pub tx_gas_limit_cap: Option<u64>,
+}
+
+impl GasSchedule {
+    /// The number of calldata tokens per non-zero byte.
+    pub const TOKENS_PER_NON_ZERO_BYTE: u64 = 4;
+
+    ///
+    /// Returns the gas schedule of `evm_version`.
+    ///
+    pub fn new(evm_version: EVMVersion) -> Self {
+        let is_berlin = evm_version >= EVMVersion::Berlin;
+        let is_london = evm_version >= EVMVersion::London;
+
+        let sload = match evm_version {
+            EVMVersion::Homestead => 50,
+            _ if evm_version < EVMVersion::Istanbul => 200,
+            _ => 800,
+        };
+        let is_tangerine_whistle = evm_version >= EVMVersion::TangerineWhistle;
+        let is_cancun = evm_version >= EVMVersion::Cancun;
+
+        let balance = match evm_version {
+            EVMVersion::Homestead => 20,
+            _ if evm_version < EVMVersion::Istanbul => 400,
+            _ => 700,
+        };
+        let extcode = if is_tangerine_whistle { 700 } else { 20 };
+        let extcodehash = match evm_version {
+            _ if evm_version < EVMVersion::Constantinople => None,
+            _ if evm_version < EVMVersion::Istanbul => Some(400),
+            _ => Some(700),
+        };
+        let call = if is_tangerine_whistle { 700 } else { 40 };
+        let sstore_dirty = match evm_version {
+            EVMVersion::Constantinople => Some(200),
+            _ if evm_version < EVMVersion::Istanbul => None,
+            _ if evm_version < EVMVersion::Berlin => Some(800),
+            _ => Some(100),
+        };
+
+        Self {
+            sload_cold: if is_berlin { 2100 } else { sload },
+            sload_warm: if is_berlin { 100 } else { sload },
+            sstore_set: 20000,
+            sstore_reset: if is_berlin { 2900 } else { 5000 },
+            sstore_clears_refund: if is_london { 4800 } else { 15000 },
+            sstore_dirty,
+            sstore_sentry: (evm_version >= EVMVersion::Istanbul).then_some(2300),
+            tload: is_cancun.then_some(100),
+            tstore: is_cancun.then_some(100),
+            balance_cold: if is_berlin { 2600 } else { balance },
+            balance_warm: if is_berlin { 100 } else { balance },
+            extcode_cold: if is_berlin { 2600 } else { extcode },
+            extcode_warm: if is_berlin { 100 } else { extcode },
+            extcodehash_cold: if is_berlin { Some(2600) } else { extcodehash },
+            extcodehash_warm: if is_berlin { Some(100) } else { extcodehash },
+            call_cold: if is_berlin { 2600 } else { call },
+            call_warm: if is_berlin { 100 } else { call },
+            call_value: 9000,
+            call_stipend: 2300,
+            call_new_account: 25000,
+            selfdestruct: if is_tangerine_whistle { 5000 } else { 0 },
+            selfdestruct_new_account: if is_tangerine_whistle { 25000 } else { 0 },
+            selfdestruct_refund: if is_london { 0 } else { 24000 },
+            max_refund_quotient: if is_london { 5 } else { 2 },
+            exp_byte: if evm_version >= EVMVersion::SpuriousDragon {
+                50
+            } else {
+                10
+            },
+            mcopy: is_cancun.then_some(3),
+            copy_word: 3,
+            memory_word: 3,
+            memory_quadratic_divisor: 512,
+            tx: 21000,
+            tx_create: 53000,
+            tx_data_zero: 4,
+            tx_data_non_zero: if evm_version >= EVMVersion::Istanbul {
+                16
+            } else {
+                68
+            },
+            tx_init_code_word: (evm_version >= EVMVersion::Shanghai).then_some(2),
+            tx_access_list_address: is_berlin.then_some(2400),
+            tx_access_list_storage_key: is_berlin.then_some(1900),
+            tx_calldata_floor_per_token: (evm_version >= EVMVersion::Prague).then_some(10),
+            tx_gas_limit_cap: (evm_version >= EVMVersion::Osaka).then_some(1 << 24),
+        }
+    }
+
+    ///
+    /// Returns the total cost of memory of `words` size.
+    ///
+    /// The cost saturates at `u64::MAX`, which exceeds any block gas limit.
+    ///
+    pub fn memory_cost(&self, words: u64) -> u64 {
+        let words = words as u128;
+        let cost = (self.memory_word as u128) * words
+            + words * words / (self.memory_quadratic_divisor as u128);
+        u64::try_from(cost).unwrap_or(u64::MAX)
+    }
+
+    ///
+    /// Returns the cost of expanding memory from `from_words` to `to_words` size.
+    ///
+    pub fn memory_expansion_cost(&self, from_words: u64, to_words: u64) -> u64 {
+        self.memory_cost(to_words)
+            .saturating_sub(self.memory_cost(from_words))
+    }
+
+    ///
+    /// Returns the intrinsic gas of a transaction with `calldata`, excluding access lists.
+    ///
+    /// If the calldata floor cost is charged, the greater of the standard and floor costs is returned.
+    ///
+    pub fn intrinsic_gas(&self, calldata: &[u8], is_create: bool) -> u64 {
+        let zero_bytes = calldata.iter().filter(|byte| **byte == 0).count() as u64;
+        let non_zero_bytes = calldata.len() as u64 - zero_bytes;
+
+        let mut gas =
+            self.tx + zero_bytes * self.tx_data_zero + non_zero_bytes * self.tx_data_non_zero;
+        if is_create {
+            gas += self.tx_create - self.tx;
+            if let Some(tx_init_code_word) = self.tx_init_code_word {
+                gas += (calldata.len() as u64).div_ceil(32) * tx_init_code_word;
+            }
+        }
+
+        match self.tx_calldata_floor_per_token {
+            Some(floor_per_token) => {
+                let tokens = zero_bytes + non_zero_bytes * Self::TOKENS_PER_NON_ZERO_BYTE;
+                gas.max(self.tx + tokens * floor_per_token)
+            }
+            None => gas,
+        }
+    }
+}
+
+impl From<EVMVersion> for GasSchedule {
+    fn from(evm_version: EVMVersion) -> Self {
+        Self::new(evm_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::evm_version::EVMVersion;
+
+    use super::GasSchedule;
+
+    #[test]
+    fn sload() {
+        let costs = [
+            (EVMVersion::Homestead, 50, 50),
+            (EVMVersion::TangerineWhistle, 200, 200),
+            (EVMVersion::Petersburg, 200, 200),
+            (EVMVersion::Istanbul, 800, 800),
+            (EVMVersion::Berlin, 2100, 100),
+            (EVMVersion::Osaka, 2100, 100),
+        ];
+        for (evm_version, cold, warm) in costs {
+            let schedule = GasSchedule::new(evm_version);
+            assert_eq!(schedule.sload_cold, cold, "{evm_version}");
+            assert_eq!(schedule.sload_warm, warm, "{evm_version}");
+        }
+    }
+
+    #[test]
+    fn sstore() {
+        let istanbul = GasSchedule::new(EVMVersion::Istanbul);
+        assert_eq!(istanbul.sstore_reset, 5000);
+        assert_eq!(istanbul.sstore_clears_refund, 15000);
+        assert_eq!(istanbul.sstore_sentry, Some(2300));
+
+        let berlin = GasSchedule::new(EVMVersion::Berlin);
+        assert_eq!(berlin.sstore_reset + berlin.sload_cold, 5000);
+        assert_eq!(berlin.sstore_clears_refund, 15000);
+
+        let london = GasSchedule::new(EVMVersion::London);
+        assert_eq!(london.sstore_clears_refund, 4800);
+        assert_eq!(london.selfdestruct_refund, 0);
+        assert_eq!(london.max_refund_quotient, 5);
+
+        assert_eq!(GasSchedule::new(EVMVersion::Petersburg).sstore_sentry, None);
+    }
+
+    #[test]
+    fn call() {
+        assert_eq!(GasSchedule::new(EVMVersion::Homestead).call_cold, 40);
+        assert_eq!(
+            GasSchedule::new(EVMVersion::TangerineWhistle).call_cold,
+            700
+        );
+
+        let cancun = GasSchedule::new(EVMVersion::Cancun);
+        assert_eq!(cancun.call_cold, 2600);
+        assert_eq!(cancun.call_warm, 100);
+        assert_eq!(cancun.call_value, 9000);
+        assert_eq!(cancun.call_stipend, 2300);
+        assert_eq!(cancun.call_new_account, 25000);
+    }
+
+    #[test]
+    fn account_access() {
+        let costs = [
+            (EVMVersion::Homestead, 20, 20, None),
+            (EVMVersion::TangerineWhistle, 400, 700, None),
+            (EVMVersion::Constantinople, 400, 700, Some(400)),
+            (EVMVersion::Istanbul, 700, 700, Some(700)),
+            (EVMVersion::Berlin, 2600, 2600, Some(2600)),
+        ];
+        for (evm_version, balance, extcode, extcodehash) in costs {
+            let schedule = GasSchedule::new(evm_version);
+            assert_eq!(schedule.balance_cold, balance, "{evm_version}");
+            assert_eq!(schedule.extcode_cold, extcode, "{evm_version}");
+            assert_eq!(schedule.extcodehash_cold, extcodehash, "{evm_version}");
+        }
+
+        let berlin = GasSchedule::new(EVMVersion::Berlin);
+        assert_eq!(berlin.balance_warm, 100);
+        assert_eq!(berlin.extcode_warm, 100);
+        assert_eq!(berlin.extcodehash_warm, Some(100));
+    }
+
+    #[test]
+    fn sstore_net_metering() {
+        let costs = [
+            (EVMVersion::Byzantium, None),
+            (EVMVersion::Constantinople, Some(200)),
+            (EVMVersion::Petersburg, None),
+            (EVMVersion::Istanbul, Some(800)),
+            (EVMVersion::Berlin, Some(100)),
+        ];
+        for (evm_version, sstore_dirty) in costs {
+            assert_eq!(
+                GasSchedule::new(evm_version).sstore_dirty,
+                sstore_dirty,
+                "{evm_version}"
+            );
+        }
+    }
+
+    #[test]
+    fn selfdestruct() {
+        let homestead = GasSchedule::new(EVMVersion::Homestead);
+        assert_eq!(homestead.selfdestruct, 0);
+        assert_eq!(homestead.selfdestruct_new_account, 0);
+
+        let tangerine_whistle = GasSchedule::new(EVMVersion::TangerineWhistle);
+        assert_eq!(tangerine_whistle.selfdestruct, 5000);
+        assert_eq!(tangerine_whistle.selfdestruct_new_account, 25000);
+        assert_eq!(tangerine_whistle.selfdestruct_refund, 24000);
+    }
+
+    #[test]
+    fn cancun() {
+        let shanghai = GasSchedule::new(EVMVersion::Shanghai);
+        assert_eq!(shanghai.tload, None);
+        assert_eq!(shanghai.tstore, None);
+        assert_eq!(shanghai.mcopy, None);
+
+        let cancun = GasSchedule::new(EVMVersion::Cancun);
+        assert_eq!(cancun.tload, Some(100));
+        assert_eq!(cancun.tstore, Some(100));
+        assert_eq!(cancun.mcopy, Some(3));
+        assert_eq!(cancun.copy_word, 3);
+    }
+
+    #[test]
+    fn memory_expansion() {
+        let schedule = GasSchedule::new(EVMVersion::Cancun);
+        assert_eq!(schedule.memory_cost(0), 0);
+        assert_eq!(schedule.memory_cost(1), 3);
+        assert_eq!(schedule.memory_cost(1024), 5120);
+        assert_eq!(schedule.memory_expansion_cost(1, 1024), 5117);
+        assert_eq!(schedule.memory_expansion_cost(1024, 1), 0);
+    }
+
+    #[test]
+    fn memory_cost_saturates() {
+        let schedule = GasSchedule::new(EVMVersion::Cancun);
+        assert_eq!(schedule.memory_cost(1 << 33), (3 << 33) + (1 << 57));
+        assert_eq!(schedule.memory_cost(u64::MAX), u64::MAX);
+        assert_eq!(schedule.memory_expansion_cost(0, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn intrinsic_gas() {
+        let calldata = [0x00, 0x01];
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Homestead).intrinsic_gas(&calldata, false),
+            21072
+        );
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Istanbul).intrinsic_gas(&calldata, false),
+            21020
+        );
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Paris).intrinsic_gas(&[0x01; 64], true),
+            54024
+        );
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Shanghai).intrinsic_gas(&[0x01; 64], true),
+            54028
+        );
+    }
+
+    #[test]
+    fn intrinsic_gas_floor() {
+        let calldata = [0x01; 100];
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Cancun).intrinsic_gas(&calldata, false),
+            22600
+        );
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Prague).intrinsic_gas(&calldata, false),
+            25000
+        );
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Prague).intrinsic_gas(&[], false),
+            21000
+        );
+    }
+
+    #[test]
+    fn tx_gas_limit_cap() {
+        assert_eq!(GasSchedule::new(EVMVersion::Prague).tx_gas_limit_cap, None);
+        assert_eq!(
+            GasSchedule::new(EVMVersion::Osaka).tx_gas_limit_cap,
+            Some(16_777_216)
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//!
+//! EVM version.
+//!
+
+pub mod feature;
+pub mod gas_schedule;
+
+use crate::string_enum::string_enum;
+
+use self::feature::EVMFeature;
+use self::gas_schedule::GasSchedule;
+
+string_enum! {
+    ///
+    /// EVM version.
+    ///
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+    )]
+    pub enum EVMVersion("EVM version") {
+        /// The corresponding EVM version.
+        Homestead => "homestead",
+        /// The corresponding EVM version.
+        TangerineWhistle => "tangerineWhistle",
+        /// The corresponding EVM version.
+        SpuriousDragon => "spuriousDragon",
+        /// The corresponding EVM version.
+        Byzantium => "byzantium",
+        /// The corresponding EVM version.
+        Constantinople => "constantinople",
+        /// The corresponding EVM version.
+        Petersburg => "petersburg",
+        /// The corresponding EVM version.
+        Istanbul => "istanbul",
+        /// The corresponding EVM version.
+        Berlin => "berlin",
+        /// The corresponding EVM version.
+        London => "london",
+        /// The corresponding EVM version.
+        Paris => "paris",
+        /// The corresponding EVM version.
+        Shanghai => "shanghai",
+        /// The corresponding EVM version.
+        Cancun => "cancun",
+        /// The corresponding EVM version.
+        Prague => "prague",
+        /// The corresponding EVM version.
+        Osaka => "osaka",
+    }
+}
+
+///
+/// The `solc` support of an EVM version.
+///
+struct SolcSupport {
+    /// The EVM version.
+    evm_version: EVMVersion,
+    /// The first `solc` version accepting the EVM version.
+    since: (u64, u64, u64),
+    /// The first `solc` version using the EVM version by default.
+    default_since: Option<(u64, u64, u64)>,
+}
+
+///
+/// The `solc` support table, ordered by EVM version.
+///
+/// A new hardfork is added to `solc` support with a single entry here.
+///
+const SOLC_SUPPORT: [SolcSupport; EVMVersion::ALL.len()] = [
+    SolcSupport {
+        evm_version: EVMVersion::Homestead,
+        since: (0, 4, 21),
+        default_since: None,
+    },
+    SolcSupport {
+        evm_version: EVMVersion::TangerineWhistle,
+        since: (0, 4, 21),
+        default_since: None,
+    },
+    SolcSupport {
+        evm_version: EVMVersion::SpuriousDragon,
+        since: (0, 4, 21),
+        default_since: None,
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Byzantium,
+        since: (0, 4, 21),
+        default_since: Some((0, 4, 21)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Constantinople,
+        since: (0, 4, 21),
+        default_since: None,
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Petersburg,
+        since: (0, 5, 5),
+        default_since: Some((0, 5, 5)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Istanbul,
+        since: (0, 5, 13),
+        default_since: Some((0, 5, 14)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Berlin,
+        since: (0, 8, 5),
+        default_since: Some((0, 8, 5)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::London,
+        since: (0, 8, 7),
+        default_since: Some((0, 8, 7)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Paris,
+        since: (0, 8, 18),
+        default_since: Some((0, 8, 18)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Shanghai,
+        since: (0, 8, 20),
+        default_since: Some((0, 8, 20)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Cancun,
+        since: (0, 8, 24),
+        default_since: Some((0, 8, 25)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Prague,
+        since: (0, 8, 27),
+        default_since: Some((0, 8, 30)),
+    },
+    SolcSupport {
+        evm_version: EVMVersion::Osaka,
+        since: (0, 8, 29),
+        default_since: Some((0, 8, 31)),
+    },
+];
+
+const _: () = {
+    let mut index = 0;
+    while index < SOLC_SUPPORT.len() {
+        assert!(
+            SOLC_SUPPORT[index].evm_version as usize == index,
+            "The solc support table must list every EVM version in order"
+        );
+        index += 1;
+    }
+};
+
+impl EVMVersion {
+    ///
+    /// Returns the gas schedule of the EVM version.
+    ///
+    pub fn gas_schedule(&self) -> GasSchedule {
+        GasSchedule::new(*self)
+    }
+
+    ///
+    /// Returns the first `solc` version accepting the EVM version.
+    ///
+    pub fn solc_version_since(&self) -> semver::Version {
+        let (major, minor, patch) = SOLC_SUPPORT[*self as usize].since;
+        semver::Version::new(major, minor, patch)
+    }
+
+    ///
+    /// Whether the EVM version is accepted by `solc` of `version`.
+    ///
+    pub fn is_supported_by_solc(&self, version: &semver::Version) -> bool {
+        Self::supported_by_solc(version).is_some_and(|range| range.contains(self))
+    }
+
+    ///
+    /// Returns the range of EVM versions accepted by `solc` of `version`.
+    ///
+    /// Returns `None` if the `solc` version predates EVM version selection.
+    ///
+    pub fn supported_by_solc(version: &semver::Version) -> Option<std::ops::RangeInclusive<Self>> {
+        let version = (version.major, version.minor, version.patch);
+        let first = SOLC_SUPPORT.iter().find(|entry| entry.since <= version)?;
+        let last = SOLC_SUPPORT
+            .iter()
+            .rev()
+            .find(|entry| entry.since <= version)?;
+        Some(first.evm_version..=last.evm_version)
+    }
+
+    ///
+    /// Returns the EVM version used by `solc` of `version` by default.
+    ///
+    /// Returns `None` if the `solc` version predates EVM version selection.
+    ///
+    pub fn default_for_solc(version: &semver::Version) -> Option<Self> {
+        let version = (version.major, version.minor, version.patch);
+        SOLC_SUPPORT
+            .iter()
+            .rev()
+            .find(|entry| entry.default_since.is_some_and(|since| since <= version))
+            .map(|entry| entry.evm_version)
+    }
+
+    ///
+    /// Whether the EVM version supports `feature`.
+    ///
+    pub fn supports(&self, feature: EVMFeature) -> bool {
+        *self >= feature.since()
+    }
+
+    ///
+    /// Returns the features supported by the EVM version.
+    ///
+    pub fn features(&self) -> impl Iterator<Item = EVMFeature> + '_ {
+        EVMFeature::ALL
+            .into_iter()
+            .filter(|feature| self.supports(*feature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::feature::EVMFeature;
+
+    #[test]
+    fn supports() {
+        assert!(!super::EVMVersion::Homestead.supports(EVMFeature::Revert));
+        assert!(super::EVMVersion::Byzantium.supports(EVMFeature::ReturnDataCopy));
+        assert!(!super::EVMVersion::Byzantium.supports(EVMFeature::Create2));
+        assert!(super::EVMVersion::Petersburg.supports(EVMFeature::Create2));
+        assert!(super::EVMVersion::Istanbul.supports(EVMFeature::ChainId));
+        assert!(super::EVMVersion::London.supports(EVMFeature::BaseFee));
+        assert!(!super::EVMVersion::Paris.supports(EVMFeature::Push0));
+        assert!(super::EVMVersion::Shanghai.supports(EVMFeature::Push0));
+        assert!(!super::EVMVersion::Shanghai.supports(EVMFeature::TransientStorage));
+        assert!(super::EVMVersion::Cancun.supports(EVMFeature::MCopy));
+        assert!(super::EVMVersion::Prague.supports(EVMFeature::SelfDestructInSameTransactionOnly));
+    }
+
+    #[test]
+    fn features() {
+        assert_eq!(super::EVMVersion::Homestead.features().count(), 0);
+        assert_eq!(
+            super::EVMVersion::Shanghai.features().last(),
+            Some(EVMFeature::Push0)
+        );
+        assert_eq!(
+            super::EVMVersion::Prague.features().count(),
+            EVMFeature::ALL.len() - 1
+        );
+        assert_eq!(
+            super::EVMVersion::Osaka.features().count(),
+            EVMFeature::ALL.len()
+        );
+    }
+
+    #[test]
+    fn default_for_solc() {
+        assert_eq!(
+            super::EVMVersion::default_for_solc(&semver::Version::new(0, 4, 20)),
+            None
+        );
+        assert_eq!(
+            super::EVMVersion::default_for_solc(&semver::Version::new(0, 4, 26)),
+            Some(super::EVMVersion::Byzantium)
+        );
+        assert_eq!(
+            super::EVMVersion::default_for_solc(&semver::Version::new(0, 5, 13)),
+            Some(super::EVMVersion::Petersburg)
+        );
+        assert_eq!(
+            super::EVMVersion::default_for_solc(&semver::Version::new(0, 8, 24)),
+            Some(super::EVMVersion::Shanghai)
+        );
+        assert_eq!(
+            super::EVMVersion::default_for_solc(&semver::Version::new(0, 8, 30)),
+            Some(super::EVMVersion::Prague)
+        );
+        assert_eq!(
+            super::EVMVersion::default_for_solc(&semver::Version::new(0, 9, 0)),
+            Some(super::EVMVersion::Osaka)
+        );
+    }
+
+    #[test]
+    fn supported_by_solc() {
+        assert_eq!(
+            super::EVMVersion::supported_by_solc(&semver::Version::new(0, 4, 11)),
+            None
+        );
+        assert_eq!(
+            super::EVMVersion::supported_by_solc(&semver::Version::new(0, 5, 0)),
+            Some(super::EVMVersion::Homestead..=super::EVMVersion::Constantinople)
+        );
+        assert_eq!(
+            super::EVMVersion::supported_by_solc(&semver::Version::new(0, 8, 28)),
+            Some(super::EVMVersion::Homestead..=super::EVMVersion::Prague)
+        );
+        assert!(super::EVMVersion::Cancun.is_supported_by_solc(&semver::Version::new(0, 8, 24)));
+        assert!(!super::EVMVersion::Cancun.is_supported_by_solc(&semver::Version::new(0, 8, 23)));
+        assert!(super::EVMVersion::Osaka.is_supported_by_solc(
+            &semver::Version::parse("0.8.29-nightly").expect("Always valid")
+        ));
+    }
+
+    #[test]
+    fn from_str_osaka() {
+        assert_eq!(
+            "osaka".parse::<super::EVMVersion>().expect("Always valid"),
+            super::EVMVersion::Osaka
+        );
+        assert_eq!(super::EVMVersion::Osaka.to_string(), "osaka");
+    }
+
+    #[test]
+    fn string_round_trip() {
+        for evm_version in super::EVMVersion::iter() {
+            assert_eq!(
+                evm_version
+                    .to_string()
+                    .parse::<super::EVMVersion>()
+                    .expect("Always valid"),
+                evm_version
+            );
+            assert_eq!(
+                serde_json::to_string(&evm_version).expect("Always valid"),
+                format!("\"{evm_version}\"")
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_error_lists_all() {
+        let error = "frontier"
+            .parse::<super::EVMVersion>()
+            .expect_err("Always invalid")
+            .to_string();
+        for evm_version in super::EVMVersion::ALL {
+            assert!(error.contains(evm_version.as_str()), "{error}");
+        }
+    }
+
+    #[test]
+    fn features_ordered() {
+        for pair in EVMFeature::ALL.windows(2) {
+            assert!(pair[0].since() <= pair[1].since());
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//!
+//! EraVM bytecode hash utilities.
+//!
+
+use crate::hash::representation::fmt_hexadecimal;
+use crate::hash::representation::Hexadecimal;
+use crate::hash::sha256::Sha256;
+
+///
+/// EraVM bytecode hash utilities.
+///
+/// The versioned bytecode hash consists of:
+/// 1. The version byte.
+/// 2. The marker byte, which is zero for deployed bytecode and one for bytecode being constructed.
+/// 3. The bytecode length in 32-byte words as a 2-byte big-endian integer.
+/// 4. The last 28 bytes of the SHA-256 hash of the bytecode.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(
+    into = "Hexadecimal<{ crate::BYTE_LENGTH_FIELD }>",
+    try_from = "Hexadecimal<{ crate::BYTE_LENGTH_FIELD }>"
+)]
+pub struct EraVMBytecode {
+    /// Binary representation.
+    bytes: [u8; crate::BYTE_LENGTH_FIELD],
+}
+
+impl EraVMBytecode {
+    /// The supported bytecode hash version.
+    pub const VERSION: u8 = 1;
+
+    /// The marker of deployed bytecode.
+    pub const MARKER_DEPLOYED: u8 = 0;
+
+    /// The marker of bytecode being constructed.
+    pub const MARKER_CONSTRUCTED: u8 = 1;
+
+    /// The number of bytes preceding the truncated SHA-256 hash.
+    pub const PREFIX_SIZE: usize = 4;
+
+    ///
+    /// Computes the versioned hash of `bytecode`.
+    ///
+    /// The bytecode must consist of an odd number of 32-byte words that fits into 2 bytes.
+    ///
+    pub fn from_slice(bytecode: &[u8]) -> anyhow::Result<Self> {
+        if bytecode.len() % crate::BYTE_LENGTH_FIELD != 0 {
+            anyhow::bail!(
+                "The bytecode size {} is not a multiple of {}",
+                bytecode.len(),
+                crate::BYTE_LENGTH_FIELD,
+            );
+        }
+        let word_count = bytecode.len() / crate::BYTE_LENGTH_FIELD;
+        if word_count % 2 == 0 {
+            anyhow::bail!("The bytecode word count {word_count} must be odd");
+        }
+        let word_count = u16::try_from(word_count).map_err(|_| {
+            anyhow::anyhow!(
+                "The bytecode word count {word_count} exceeds the maximum of {}",
+                u16::MAX
+            )
+        })?;
+
+        let mut bytes: [u8; crate::BYTE_LENGTH_FIELD] = Sha256::from_slice(bytecode)
+            .as_bytes()
+            .try_into()
+            .expect("Always valid");
+        bytes[0] = Self::VERSION;
+        bytes[1] = Self::MARKER_DEPLOYED;
+        bytes[2..Self::PREFIX_SIZE].copy_from_slice(word_count.to_be_bytes().as_slice());
+        Ok(Self { bytes })
+    }
+
+    ///
+    /// Parses an existing versioned bytecode hash.
+    ///
+    pub fn from_bytes(bytes: [u8; crate::BYTE_LENGTH_FIELD]) -> anyhow::Result<Self> {
+        if bytes[0] != Self::VERSION {
+            anyhow::bail!(
+                "Unsupported bytecode hash version {}: expected {}",
+                bytes[0],
+                Self::VERSION
+            );
+        }
+        if ![Self::MARKER_DEPLOYED, Self::MARKER_CONSTRUCTED].contains(&bytes[1]) {
+            anyhow::bail!("Invalid bytecode hash marker {}", bytes[1]);
+        }
+        let word_count = u16::from_be_bytes([bytes[2], bytes[3]]);
+        if word_count % 2 == 0 {
+            anyhow::bail!("The bytecode hash word count {word_count} must be odd");
+        }
+        Ok(Self { bytes })
+    }
+
+    ///
+    /// Returns the bytecode length in 32-byte words.
+    ///
+    pub fn word_count(&self) -> u16 {
+        u16::from_be_bytes([self.bytes[2], self.bytes[3]])
+    }
+
+    ///
+    /// Returns the bytecode length in bytes.
+    ///
+    pub fn byte_length(&self) -> usize {
+        (self.word_count() as usize) * crate::BYTE_LENGTH_FIELD
+    }
+
+    ///
+    /// Whether the hash is marked as the hash of bytecode being constructed.
+    ///
+    pub fn is_constructed(&self) -> bool {
+        self.bytes[1] == Self::MARKER_CONSTRUCTED
+    }
+
+    ///
+    /// Returns a reference to the 32-byte versioned hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for EraVMBytecode {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; crate::BYTE_LENGTH_FIELD] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid EraVM bytecode hash length: expected {} bytes, found {}",
+                crate::BYTE_LENGTH_FIELD,
+                bytes.len()
+            )
+        })?;
+        Self::from_bytes(bytes)
+    }
+}
+
+impl AsRef<[u8]> for EraVMBytecode {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<EraVMBytecode> for Hexadecimal<{ crate::BYTE_LENGTH_FIELD }> {
+    fn from(hash: EraVMBytecode) -> Self {
+        Self::from(hash.bytes)
+    }
+}
+
+impl TryFrom<Hexadecimal<{ crate::BYTE_LENGTH_FIELD }>> for EraVMBytecode {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        representation: Hexadecimal<{ crate::BYTE_LENGTH_FIELD }>,
+    ) -> Result<Self, Self::Error> {
+        Self::from_bytes(representation.bytes)
+    }
+}
+
+impl std::fmt::Display for EraVMBytecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "0x")?;
+        fmt_hexadecimal(f, self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn single_word() {
+        let hash = super::EraVMBytecode::from_slice(&[0_u8; crate::BYTE_LENGTH_FIELD])
+            .expect("Always valid");
+
+        assert_eq!(
+            hash.to_string(),
+            "0x01000001f862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925"
+        );
+        assert_eq!(hash.word_count(), 1);
+        assert_eq!(hash.byte_length(), crate::BYTE_LENGTH_FIELD);
+        assert!(!hash.is_constructed());
+    }
+
+    #[test]
+    fn parse() {
+        let hash = super::EraVMBytecode::from_slice(&[0xAA_u8; 3 * crate::BYTE_LENGTH_FIELD])
+            .expect("Always valid");
+        let bytes: [u8; crate::BYTE_LENGTH_FIELD] =
+            hash.as_bytes().try_into().expect("Always valid");
+
+        let parsed = super::EraVMBytecode::from_bytes(bytes).expect("Always valid");
+        assert_eq!(parsed, hash);
+        assert_eq!(parsed.word_count(), 3);
+    }
+
+    #[test]
+    fn error_unaligned() {
+        assert!(super::EraVMBytecode::from_slice(&[0_u8; crate::BYTE_LENGTH_FIELD + 1]).is_err());
+    }
+
+    #[test]
+    fn error_even_word_count() {
+        assert!(super::EraVMBytecode::from_slice(&[0_u8; 2 * crate::BYTE_LENGTH_FIELD]).is_err());
+        assert!(super::EraVMBytecode::from_slice(&[]).is_err());
+    }
+
+    #[test]
+    fn error_too_long() {
+        assert!(super::EraVMBytecode::from_slice(
+            vec![0_u8; (u16::MAX as usize + 2) * crate::BYTE_LENGTH_FIELD].as_slice()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn error_parse_version() {
+        let mut bytes = [0_u8; crate::BYTE_LENGTH_FIELD];
+        bytes[0] = 2;
+        bytes[3] = 1;
+
+        assert!(super::EraVMBytecode::from_bytes(bytes).is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//!
+//! IPFS content identifier.
+//!
+
+use std::str::FromStr;
+
+use crate::hash::ipfs::codec::Codec;
+use crate::hash::ipfs::IPFS;
+
+///
+/// IPFS content identifier.
+///
+/// CIDv0 is the bare SHA2-256 multihash of a `dag-pb` block, rendered in base58.
+/// CIDv1 is prefixed with the version and codec, and rendered with a multibase prefix.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CID {
+    /// The CID version.
+    version: Version,
+    /// The content codec.
+    codec: Codec,
+    /// The content multihash.
+    multihash: IPFS,
+}
+
+///
+/// IPFS content identifier version.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Version {
+    /// The legacy version without the codec prefix.
+    V0,
+    /// The self-describing version.
+    V1,
+}
+
+impl CID {
+    /// The binary prefix of CIDv1.
+    pub const V1_PREFIX: u8 = 0x01;
+
+    /// The default multibase of CIDv1 string representations.
+    pub const V1_DEFAULT_BASE: multibase::Base = multibase::Base::Base32Lower;
+
+    ///
+    /// Creates a CIDv0 of a `dag-pb` block with `multihash`.
+    ///
+    pub fn new_v0(multihash: IPFS) -> Self {
+        Self {
+            version: Version::V0,
+            codec: Codec::DagPb,
+            multihash,
+        }
+    }
+
+    ///
+    /// Creates a CIDv1 of a `codec` block with `multihash`.
+    ///
+    pub fn new_v1(codec: Codec, multihash: IPFS) -> Self {
+        Self {
+            version: Version::V1,
+            codec,
+            multihash,
+        }
+    }
+
+    ///
+    /// Returns the CID version.
+    ///
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    ///
+    /// Returns the content codec.
+    ///
+    pub fn codec(&self) -> Codec {
+        self.codec
+    }
+
+    ///
+    /// Returns a reference to the content multihash.
+    ///
+    pub fn multihash(&self) -> &IPFS {
+        &self.multihash
+    }
+
+    ///
+    /// Converts the CID to CIDv0.
+    ///
+    /// Only `dag-pb` CIDs can be represented as CIDv0.
+    ///
+    pub fn into_v0(self) -> anyhow::Result<Self> {
+        if self.codec != Codec::DagPb {
+            anyhow::bail!(
+                "Only `{}` CIDs can be converted to CIDv0, found `{}`",
+                Codec::DagPb,
+                self.codec
+            );
+        }
+        Ok(Self::new_v0(self.multihash))
+    }
+
+    ///
+    /// Converts the CID to CIDv1.
+    ///
+    pub fn into_v1(self) -> Self {
+        Self::new_v1(self.codec, self.multihash)
+    }
+
+    ///
+    /// Returns the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        match self.version {
+            Version::V0 => self.multihash.to_vec(),
+            Version::V1 => {
+                let mut bytes = Vec::with_capacity(2 + self.multihash.as_bytes().len());
+                bytes.push(Self::V1_PREFIX);
+                bytes.push(self.codec.code());
+                bytes.extend_from_slice(self.multihash.as_bytes());
+                bytes
+            }
+        }
+    }
+
+    ///
+    /// Returns the string representation in the multibase `base`.
+    ///
+    /// CIDv0 only supports the base58 representation without the multibase prefix.
+    ///
+    pub fn to_string_of_base(&self, base: multibase::Base) -> anyhow::Result<String> {
+        match self.version {
+            Version::V0 if base == multibase::Base::Base58Btc => {
+                Ok(self.multihash.to_base58_string())
+            }
+            Version::V0 => anyhow::bail!("CIDv0 can only be represented in base58"),
+            Version::V1 => Ok(multibase::encode(base, self.to_vec())),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for CID {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes {
+            [Self::V1_PREFIX, codec, multihash @ ..] => Ok(Self::new_v1(
+                Codec::try_from(*codec)?,
+                IPFS::try_from(multihash)?,
+            )),
+            [IPFS::MULTIHASH_SHA2_256, ..] => Ok(Self::new_v0(IPFS::try_from(bytes)?)),
+            _ => anyhow::bail!("Invalid CID `0x{}`", hex::encode(bytes)),
+        }
+    }
+}
+
+impl FromStr for CID {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses either a base58 CIDv0 string, or a multibase CIDv1 string.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.starts_with(IPFS::CID_V0_PREFIX) {
+            return IPFS::from_str(string).map(Self::new_v0);
+        }
+
+        let (_base, bytes) = multibase::decode(string)
+            .map_err(|error| anyhow::anyhow!("Invalid multibase CID `{string}`: {error}"))?;
+        match Self::try_from(bytes.as_slice())? {
+            cid @ Self {
+                version: Version::V1,
+                ..
+            } => Ok(cid),
+            _ => anyhow::bail!(
+                "CIDv0 `{string}` must be represented in base58 without a multibase prefix"
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for CID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let base = match self.version {
+            Version::V0 => multibase::Base::Base58Btc,
+            Version::V1 => Self::V1_DEFAULT_BASE,
+        };
+        write!(f, "{}", self.to_string_of_base(base).expect("Always valid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hash::ipfs::codec::Codec;
+    use crate::hash::ipfs::IPFS;
+
+    #[test]
+    fn v0() {
+        let cid = IPFS::from_slice("LLVM is the Endgame".as_bytes()).to_cid_v0();
+
+        assert_eq!(
+            cid.to_string(),
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+        );
+        assert!(cid.to_string_of_base(multibase::Base::Base32Lower).is_err());
+    }
+
+    #[test]
+    fn v1_dag_pb() {
+        let cid = IPFS::from_slice("LLVM is the Endgame".as_bytes()).to_cid_v1(Codec::DagPb);
+
+        assert_eq!(
+            cid.to_string(),
+            "bafybeida3hc4eanyylwyvsg6dyq27rhpcfnnt5dymprb77jj6jzfis2reu"
+        );
+        assert_eq!(
+            cid.to_string_of_base(multibase::Base::Base16Lower)
+                .expect("Always valid"),
+            "f0170122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+        );
+    }
+
+    #[test]
+    fn v1_raw() {
+        let cid = IPFS::from_slice_raw("LLVM is the Endgame".as_bytes()).to_cid_v1(Codec::Raw);
+
+        assert_eq!(
+            cid.to_string(),
+            "bafkreigfjge5de7roxgvnseweou5d3ojlqzpvukdnp5u5r24qgusqnkk6q"
+        );
+    }
+
+    #[test]
+    fn conversion() {
+        let v0 = IPFS::from_slice("LLVM is the Endgame".as_bytes()).to_cid_v0();
+        let v1 = v0.clone().into_v1();
+
+        assert_eq!(v1.codec(), Codec::DagPb);
+        assert_eq!(v1.clone().into_v0().expect("Always valid"), v0);
+        assert!(IPFS::from_slice_raw(&[])
+            .to_cid_v1(Codec::Raw)
+            .into_v0()
+            .is_err());
+    }
+
+    #[test]
+    fn parse() {
+        for string in [
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U",
+            "bafybeida3hc4eanyylwyvsg6dyq27rhpcfnnt5dymprb77jj6jzfis2reu",
+            "bafkreigfjge5de7roxgvnseweou5d3ojlqzpvukdnp5u5r24qgusqnkk6q",
+        ]
+        .into_iter()
+        {
+            assert_eq!(
+                string
+                    .parse::<super::CID>()
+                    .expect("Always valid")
+                    .to_string(),
+                string
+            );
+        }
+        assert_eq!(
+            "f0170122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+                .parse::<super::CID>()
+                .expect("Always valid")
+                .to_string(),
+            "bafybeida3hc4eanyylwyvsg6dyq27rhpcfnnt5dymprb77jj6jzfis2reu"
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!("bafy".parse::<super::CID>().is_err());
+        assert!("zQmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+            .parse::<super::CID>()
+            .is_err());
+    }
+}
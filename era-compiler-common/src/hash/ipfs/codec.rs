@@ -0,0 +1,49 @@
+//!
+//! IPFS content codec.
+//!
+
+///
+/// IPFS content codec.
+///
+/// Defines how the content addressed by a CID is interpreted.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Codec {
+    /// The MerkleDAG protobuf codec, used for UnixFS files and directories.
+    DagPb,
+    /// The raw binary codec, used for data stored as a single block.
+    Raw,
+}
+
+impl Codec {
+    ///
+    /// Returns the multicodec code.
+    ///
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::DagPb => 0x70,
+            Self::Raw => 0x55,
+        }
+    }
+}
+
+impl TryFrom<u8> for Codec {
+    type Error = anyhow::Error;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0x70 => Ok(Self::DagPb),
+            0x55 => Ok(Self::Raw),
+            code => anyhow::bail!("Unsupported IPFS codec `0x{code:02x}`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Codec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DagPb => write!(f, "dag-pb"),
+            Self::Raw => write!(f, "raw"),
+        }
+    }
+}
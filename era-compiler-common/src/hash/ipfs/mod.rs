@@ -0,0 +1,390 @@
+//!
+//! IPFS hash utilities.
+//!
+
+pub mod cid;
+pub mod codec;
+pub mod unixfs;
+
+use std::str::FromStr;
+
+use base58::FromBase58;
+use base58::ToBase58;
+
+use crate::hash::representation::fmt_hexadecimal;
+use crate::hash::representation::Multihash;
+use crate::hash::sha256::Sha256;
+
+use self::cid::CID;
+use self::codec::Codec;
+use self::unixfs::Builder;
+
+///
+/// IPFS hash utilities.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(into = "Multihash", try_from = "Multihash")]
+pub struct IPFS {
+    /// Binary representation.
+    bytes: [u8; 2 + crate::BYTE_LENGTH_FIELD],
+}
+
+impl IPFS {
+    /// The multihash code of SHA2-256.
+    pub const MULTIHASH_SHA2_256: u8 = 0x12;
+
+    /// The multihash digest length of SHA2-256.
+    pub const MULTIHASH_SHA2_256_LENGTH: u8 = crate::BYTE_LENGTH_FIELD as u8;
+
+    /// The prefix of base58-encoded CIDv0 strings.
+    pub const CID_V0_PREFIX: &'static str = "Qm";
+
+    ///
+    /// Computes the IPFS hash for `preimage`.
+    ///
+    /// The hash is computed over the UnixFS DAG built by `ipfs add` with the default chunk size.
+    ///
+    pub fn from_slice(preimage: &[u8]) -> Self {
+        Self::from_slice_with_chunk_size(preimage, Builder::DEFAULT_CHUNK_SIZE)
+    }
+
+    ///
+    /// Computes the IPFS hash for `preimage` split into chunks of `chunk_size` bytes.
+    ///
+    pub fn from_slice_with_chunk_size(preimage: &[u8], chunk_size: usize) -> Self {
+        let mut builder = Builder::new(chunk_size);
+        builder.update(preimage);
+        builder.finalize()
+    }
+
+    ///
+    /// Computes the SHA2-256 multihash of `block` as it is, for use with the `raw` codec.
+    ///
+    /// Unlike `from_slice`, the data is not wrapped into a UnixFS `dag-pb` node.
+    ///
+    pub fn from_slice_raw(block: &[u8]) -> Self {
+        let mut bytes = [0_u8; 2 + crate::BYTE_LENGTH_FIELD];
+        bytes[0] = Self::MULTIHASH_SHA2_256;
+        bytes[1] = Self::MULTIHASH_SHA2_256_LENGTH;
+        bytes[2..].copy_from_slice(Sha256::from_slice(block).as_bytes());
+        Self::try_from(bytes.as_slice()).expect("Always valid")
+    }
+
+    ///
+    /// Computes the IPFS hash for the data read from `reader`.
+    ///
+    /// The data is not collected in memory. See `IPFSHasher` for details.
+    ///
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut hasher = IPFSHasher::default();
+        std::io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
+    }
+
+    ///
+    /// Returns a reference to the 34-byte IPFS hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Returns the base58 string representation of the IPFS hash.
+    ///
+    #[deprecated(note = "the string is no longer stored; use `to_base58_string` instead")]
+    pub fn as_base58_str(&self) -> String {
+        Multihash::from(*self).string_base58
+    }
+
+    ///
+    /// Returns the hexadecimal string representation of the IPFS hash.
+    ///
+    #[deprecated(note = "the string is no longer stored; use `to_hex_string` instead")]
+    pub fn as_hex_str(&self) -> String {
+        Multihash::from(*self).string_hex
+    }
+
+    ///
+    /// Returns the base58 string representation of the IPFS hash, also known as CIDv0.
+    ///
+    pub fn to_base58_string(&self) -> String {
+        self.bytes.to_base58()
+    }
+
+    ///
+    /// Returns the hexadecimal string representation of the IPFS hash.
+    ///
+    pub fn to_hex_string(&self) -> String {
+        hex::encode(self.bytes)
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    ///
+    /// Returns the CIDv0 of the `dag-pb` block with this hash.
+    ///
+    pub fn to_cid_v0(&self) -> CID {
+        CID::new_v0(*self)
+    }
+
+    ///
+    /// Returns the CIDv1 of the `codec` block with this hash.
+    ///
+    pub fn to_cid_v1(&self, codec: Codec) -> CID {
+        CID::new_v1(codec, *self)
+    }
+}
+
+impl TryFrom<&[u8]> for IPFS {
+    type Error = anyhow::Error;
+
+    ///
+    /// Parses a 34-byte SHA2-256 multihash.
+    ///
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 2 + crate::BYTE_LENGTH_FIELD] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid IPFS hash length: expected {} bytes, found {}",
+                2 + crate::BYTE_LENGTH_FIELD,
+                bytes.len()
+            )
+        })?;
+        if bytes[..2] != [Self::MULTIHASH_SHA2_256, Self::MULTIHASH_SHA2_256_LENGTH] {
+            anyhow::bail!(
+                "Invalid IPFS hash multihash prefix `0x{}`: only SHA2-256 `0x{:02x}{:02x}` is supported",
+                hex::encode(&bytes[..2]),
+                Self::MULTIHASH_SHA2_256,
+                Self::MULTIHASH_SHA2_256_LENGTH,
+            );
+        }
+        Ok(Self { bytes })
+    }
+}
+
+impl FromStr for IPFS {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses either a base58 CIDv0 string, or a hexadecimal multihash with or without the `0x` prefix.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.starts_with(Self::CID_V0_PREFIX) {
+            let bytes = string.from_base58().map_err(|error| {
+                anyhow::anyhow!("Invalid base58 IPFS hash `{string}`: {error:?}")
+            })?;
+            Self::try_from(bytes.as_slice())
+        } else {
+            let bytes = crate::hash::decode_hex::<{ 2 + crate::BYTE_LENGTH_FIELD }>(string)?;
+            Self::try_from(bytes.as_slice())
+        }
+    }
+}
+
+impl AsRef<[u8]> for IPFS {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<IPFS> for Multihash {
+    fn from(hash: IPFS) -> Self {
+        Self {
+            bytes: hash.bytes,
+            string_base58: hash.to_base58_string(),
+            string_hex: hash.to_hex_string(),
+        }
+    }
+}
+
+impl TryFrom<Multihash> for IPFS {
+    type Error = anyhow::Error;
+
+    fn try_from(representation: Multihash) -> Result<Self, Self::Error> {
+        Self::try_from(representation.bytes.as_slice())
+    }
+}
+
+impl std::fmt::Display for IPFS {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt_hexadecimal(f, self.as_bytes())
+    }
+}
+
+///
+/// Incremental IPFS hasher.
+///
+/// Can be used as a `std::io::Write` sink to hash data while streaming it.
+/// Only the current chunk and the links of the incomplete DAG nodes are kept in memory,
+/// which is at most one chunk plus `BRANCHING_FACTOR` links per DAG level.
+///
+#[derive(Debug, Default, Clone)]
+pub struct IPFSHasher {
+    /// The UnixFS DAG builder.
+    builder: Builder,
+}
+
+impl IPFSHasher {
+    ///
+    /// Creates a hasher splitting the data into chunks of `chunk_size` bytes.
+    ///
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        Self {
+            builder: Builder::new(chunk_size),
+        }
+    }
+
+    ///
+    /// Appends `data` to the preimage.
+    ///
+    pub fn update(&mut self, data: &[u8]) {
+        self.builder.update(data);
+    }
+
+    ///
+    /// Computes the hash of the data appended so far.
+    ///
+    pub fn finalize(self) -> IPFS {
+        self.builder.finalize()
+    }
+}
+
+impl std::io::Write for IPFSHasher {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.update(buffer);
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn base58() {
+        assert_eq!(
+            super::IPFS::from_slice("LLVM is the Endgame".as_bytes()).to_base58_string(),
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+        );
+    }
+
+    #[test]
+    fn hexadecimal() {
+        assert_eq!(
+            super::IPFS::from_slice("LLVM is the Endgame".as_bytes()).to_hex_string(),
+            "122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            super::IPFS::from_slice("LLVM is the Endgame".as_bytes()).to_string(),
+            "122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+        );
+    }
+
+    #[test]
+    fn hasher() {
+        let mut hasher = super::IPFSHasher::default();
+        hasher.update("LLVM is ".as_bytes());
+        hasher.update("the Endgame".as_bytes());
+
+        assert_eq!(
+            hasher.finalize().to_base58_string(),
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+        );
+    }
+
+    #[test]
+    fn reader() {
+        let data = vec![0xAA_u8; 1 << 20];
+
+        assert_eq!(
+            super::IPFS::from_reader(std::io::Cursor::new(data.as_slice())).expect("Always valid"),
+            super::IPFS::from_slice(data.as_slice())
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let hash = super::IPFS::from_slice("LLVM is the Endgame".as_bytes());
+        let json = serde_json::to_value(hash).expect("Always valid");
+
+        assert_eq!(
+            json["string_base58"],
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+        );
+        assert_eq!(
+            json["string_hex"],
+            "122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+        );
+        assert_eq!(
+            serde_json::from_value::<super::IPFS>(json).expect("Always valid"),
+            hash
+        );
+    }
+
+    #[test]
+    fn serde_error_prefix() {
+        let mut json = serde_json::to_value(super::IPFS::from_slice(&[])).expect("Always valid");
+        json["bytes"][0] = serde_json::Value::from(0x1b);
+
+        assert!(serde_json::from_value::<super::IPFS>(json).is_err());
+    }
+
+    #[test]
+    fn parse_base58() {
+        assert_eq!(
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+                .parse::<super::IPFS>()
+                .expect("Always valid"),
+            super::IPFS::from_slice("LLVM is the Endgame".as_bytes())
+        );
+    }
+
+    #[test]
+    fn parse_hexadecimal() {
+        let hash = super::IPFS::from_slice("LLVM is the Endgame".as_bytes());
+
+        assert_eq!(
+            "122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+                .parse::<super::IPFS>()
+                .expect("Always valid"),
+            hash
+        );
+        assert_eq!(
+            "0x122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+                .parse::<super::IPFS>()
+                .expect("Always valid"),
+            hash
+        );
+    }
+
+    #[test]
+    fn parse_error_prefix() {
+        assert!(
+            "1b2060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+                .parse::<super::IPFS>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_error_base58() {
+        assert!("QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92"
+            .parse::<super::IPFS>()
+            .is_err());
+        assert!("QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92l"
+            .parse::<super::IPFS>()
+            .is_err());
+    }
+}
@@ -0,0 +1,338 @@
+//!
+//! IPFS UnixFS file DAG builder.
+//!
+
+use crate::hash::ipfs::IPFS;
+
+///
+/// IPFS UnixFS file DAG builder.
+///
+/// Reproduces the default `ipfs add` layout: the data is split into fixed-size chunks,
+/// each stored in a UnixFS leaf node, and the leaves are linked into a balanced tree
+/// of `dag-pb` nodes with at most `BRANCHING_FACTOR` links each.
+///
+/// Only the links of the incomplete nodes are kept in memory, so the data can be streamed.
+///
+#[derive(Debug, Clone)]
+pub struct Builder {
+    /// The size of data chunks stored in the leaves.
+    chunk_size: usize,
+    /// The data of the current chunk.
+    buffer: Vec<u8>,
+    /// The links of the incomplete nodes, from the leaf level upwards.
+    levels: Vec<Vec<Link>>,
+}
+
+///
+/// Link to a child node.
+///
+#[derive(Debug, Clone)]
+struct Link {
+    /// The child node multihash.
+    hash: IPFS,
+    /// The size of the encoded child node and all its descendants.
+    total_size: u64,
+    /// The size of the file data stored in the child subtree.
+    file_size: u64,
+}
+
+impl Builder {
+    /// The default chunk size of `ipfs add`.
+    pub const DEFAULT_CHUNK_SIZE: usize = 256 * 1024;
+
+    /// The maximum number of links in a node.
+    pub const BRANCHING_FACTOR: usize = 174;
+
+    /// The protobuf wire type of variable-length integers.
+    const WIRE_TYPE_VARINT: u8 = 0;
+
+    /// The protobuf wire type of length-delimited fields.
+    const WIRE_TYPE_LENGTH_DELIMITED: u8 = 2;
+
+    /// The UnixFS `File` data type.
+    const UNIXFS_TYPE_FILE: u64 = 2;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// # Panics
+    /// If `chunk_size` is zero.
+    ///
+    pub fn new(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "The IPFS chunk size must be positive");
+
+        Self {
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size.min(Self::DEFAULT_CHUNK_SIZE)),
+            levels: Vec::new(),
+        }
+    }
+
+    ///
+    /// Appends `data` to the file.
+    ///
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let length = (self.chunk_size - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..length]);
+            data = &data[length..];
+
+            if self.buffer.len() == self.chunk_size {
+                self.flush_leaf();
+            }
+        }
+    }
+
+    ///
+    /// Builds the rest of the DAG and returns the root node hash.
+    ///
+    pub fn finalize(mut self) -> IPFS {
+        if !self.buffer.is_empty() || self.levels.is_empty() {
+            self.flush_leaf();
+        }
+
+        let mut level = 0;
+        loop {
+            let is_root = level + 1 == self.levels.len() && self.levels[level].len() == 1;
+            if is_root {
+                return self.levels[level].pop().expect("Always exists").hash;
+            }
+            if !self.levels[level].is_empty() {
+                self.flush_links(level);
+            }
+            level += 1;
+        }
+    }
+
+    ///
+    /// Stores the current chunk in a leaf node.
+    ///
+    fn flush_leaf(&mut self) {
+        let mut unixfs = Vec::with_capacity(self.buffer.len() + 16);
+        Self::write_varint_field(&mut unixfs, 1, Self::UNIXFS_TYPE_FILE);
+        if !self.buffer.is_empty() {
+            Self::write_bytes_field(&mut unixfs, 2, self.buffer.as_slice());
+        }
+        Self::write_varint_field(&mut unixfs, 3, self.buffer.len() as u64);
+
+        let mut node = Vec::with_capacity(unixfs.len() + 8);
+        Self::write_bytes_field(&mut node, 1, unixfs.as_slice());
+
+        let link = Link {
+            hash: IPFS::from_slice_raw(node.as_slice()),
+            total_size: node.len() as u64,
+            file_size: self.buffer.len() as u64,
+        };
+        self.buffer.clear();
+        self.push_link(0, link);
+    }
+
+    ///
+    /// Stores the links of `level` in a node of the next level.
+    ///
+    fn flush_links(&mut self, level: usize) {
+        let links = std::mem::take(&mut self.levels[level]);
+
+        let mut node = Vec::new();
+        let mut unixfs = Vec::new();
+        Self::write_varint_field(&mut unixfs, 1, Self::UNIXFS_TYPE_FILE);
+        Self::write_varint_field(
+            &mut unixfs,
+            3,
+            links.iter().map(|link| link.file_size).sum(),
+        );
+        for link in links.iter() {
+            let mut encoded = Vec::with_capacity(48);
+            Self::write_bytes_field(&mut encoded, 1, link.hash.as_bytes());
+            Self::write_bytes_field(&mut encoded, 2, &[]);
+            Self::write_varint_field(&mut encoded, 3, link.total_size);
+            Self::write_bytes_field(&mut node, 2, encoded.as_slice());
+
+            Self::write_varint_field(&mut unixfs, 4, link.file_size);
+        }
+        Self::write_bytes_field(&mut node, 1, unixfs.as_slice());
+
+        let link = Link {
+            hash: IPFS::from_slice_raw(node.as_slice()),
+            total_size: node.len() as u64 + links.iter().map(|link| link.total_size).sum::<u64>(),
+            file_size: links.iter().map(|link| link.file_size).sum(),
+        };
+        self.push_link(level + 1, link);
+    }
+
+    ///
+    /// Appends `link` to `level`, first linking the level nodes if it is full.
+    ///
+    fn push_link(&mut self, level: usize, link: Link) {
+        if self.levels.len() == level {
+            self.levels.push(Vec::with_capacity(Self::BRANCHING_FACTOR));
+        }
+        if self.levels[level].len() == Self::BRANCHING_FACTOR {
+            self.flush_links(level);
+        }
+        self.levels[level].push(link);
+    }
+
+    ///
+    /// Writes a protobuf variable-length integer field.
+    ///
+    fn write_varint_field(buffer: &mut Vec<u8>, number: u8, value: u64) {
+        buffer.push((number << 3) | Self::WIRE_TYPE_VARINT);
+        Self::write_varint(buffer, value);
+    }
+
+    ///
+    /// Writes a protobuf length-delimited field.
+    ///
+    fn write_bytes_field(buffer: &mut Vec<u8>, number: u8, value: &[u8]) {
+        buffer.push((number << 3) | Self::WIRE_TYPE_LENGTH_DELIMITED);
+        Self::write_varint(buffer, value.len() as u64);
+        buffer.extend_from_slice(value);
+    }
+
+    ///
+    /// Writes a protobuf variable-length integer.
+    ///
+    fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            buffer.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buffer.push(value as u8);
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CHUNK_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ipfs_unixfs::file::adder::Chunker;
+    use ipfs_unixfs::file::adder::FileAdder;
+
+    fn hash(data: &[u8], chunk_size: usize) -> String {
+        let mut builder = super::Builder::new(chunk_size);
+        builder.update(data);
+        builder.finalize().to_base58_string()
+    }
+
+    fn reference_hash(data: &[u8], chunk_size: usize) -> String {
+        let mut adder = FileAdder::builder()
+            .with_chunker(Chunker::Size(chunk_size))
+            .build();
+        let mut blocks = Vec::new();
+        let mut written = 0;
+        while written < data.len() {
+            let (pushed_blocks, pushed) = adder.push(&data[written..]);
+            blocks.extend(pushed_blocks);
+            written += pushed;
+        }
+        blocks.extend(adder.finish());
+        blocks.last().expect("Always exists").0.to_string()
+    }
+
+    fn data(length: usize) -> Vec<u8> {
+        (0..length).map(|index| (index % 251) as u8).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            hash(&[], super::Builder::DEFAULT_CHUNK_SIZE),
+            "QmbFMke1KXqnYyBBWxB74N4c5SBnJMVAiMNRcGu6x1AwQH"
+        );
+    }
+
+    #[test]
+    fn single_chunk() {
+        assert_eq!(
+            hash(b"hello world\n", super::Builder::DEFAULT_CHUNK_SIZE),
+            "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o"
+        );
+        assert_eq!(
+            hash(b"foobar\n", super::Builder::DEFAULT_CHUNK_SIZE),
+            "QmRgutAxd8t7oGkSm4wmeuByG6M51wcTso6cubDdQtuEfL"
+        );
+    }
+
+    #[test]
+    fn multiple_chunks() {
+        assert_eq!(
+            hash(b"foobar\n", 2),
+            "QmRJHYTNvC3hmd9gJQARxLR1QMEincccBV53bBw524yyq6"
+        );
+    }
+
+    #[test]
+    fn default_chunk_size() {
+        for length in [
+            super::Builder::DEFAULT_CHUNK_SIZE - 1,
+            super::Builder::DEFAULT_CHUNK_SIZE,
+            super::Builder::DEFAULT_CHUNK_SIZE + 1,
+            3 * super::Builder::DEFAULT_CHUNK_SIZE + 12345,
+        ] {
+            let data = data(length);
+            assert_eq!(
+                hash(data.as_slice(), super::Builder::DEFAULT_CHUNK_SIZE),
+                reference_hash(data.as_slice(), super::Builder::DEFAULT_CHUNK_SIZE),
+                "Mismatch for {length} bytes"
+            );
+        }
+    }
+
+    #[test]
+    fn balanced_tree() {
+        let branching_factor = super::Builder::BRANCHING_FACTOR;
+        for chunks in [
+            branching_factor - 1,
+            branching_factor,
+            branching_factor + 1,
+            branching_factor * branching_factor,
+            branching_factor * branching_factor + 1,
+            2 * branching_factor * branching_factor + 3,
+        ] {
+            let data = data(chunks);
+            assert_eq!(
+                hash(data.as_slice(), 1),
+                reference_hash(data.as_slice(), 1),
+                "Mismatch for {chunks} chunks"
+            );
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let data = data(5000);
+
+        let mut builder = super::Builder::new(7);
+        for chunk in data.chunks(13) {
+            builder.update(chunk);
+        }
+        assert_eq!(
+            builder.finalize().to_base58_string(),
+            hash(data.as_slice(), 7)
+        );
+    }
+
+    #[test]
+    fn bounded_memory() {
+        let mut builder = super::Builder::new(4);
+        for chunk in data(4 * super::Builder::BRANCHING_FACTOR * 3 + 2).chunks(3) {
+            builder.update(chunk);
+            assert!(builder.buffer.len() < 4);
+            assert!(builder
+                .levels
+                .iter()
+                .all(|links| links.len() <= super::Builder::BRANCHING_FACTOR));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The IPFS chunk size must be positive")]
+    fn zero_chunk_size() {
+        super::Builder::new(0);
+    }
+}
@@ -2,41 +2,59 @@
 //! Keccak-256 hash utilities.
 //!
 
+use std::str::FromStr;
+
 use sha3::digest::FixedOutput;
 use sha3::Digest;
 
+use crate::hash::representation::fmt_hexadecimal;
+use crate::hash::representation::Hexadecimal;
+
 ///
 /// Keccak-256 hash utilities.
 ///
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(
+    into = "Hexadecimal<{ Keccak256::BYTE_LENGTH }>",
+    from = "Hexadecimal<{ Keccak256::BYTE_LENGTH }>"
+)]
 pub struct Keccak256 {
     /// Binary representation.
-    bytes: [u8; crate::BYTE_LENGTH_FIELD],
-    /// Hexadecimal string representation.
-    string: String,
+    bytes: [u8; Self::BYTE_LENGTH],
 }
 
 impl Keccak256 {
+    /// The Keccak-256 hash byte-length.
+    pub const BYTE_LENGTH: usize = crate::BYTE_LENGTH_FIELD;
+
     ///
     /// Computes the `keccak256` hash for `preimage`.
     ///
     pub fn from_slice(preimage: &[u8]) -> Self {
         let bytes = sha3::Keccak256::digest(preimage).into();
-        let string = format!("0x{}", hex::encode(bytes));
-        Self { bytes, string }
+        Self { bytes }
     }
 
     ///
     /// Computes the `keccak256` hash for an array of `preimages`.
     ///
     pub fn from_slices<R: AsRef<[u8]>>(preimages: &[R]) -> Self {
-        let mut hasher = sha3::Keccak256::new();
+        let mut hasher = Keccak256Hasher::default();
         for preimage in preimages.iter() {
-            hasher.update(preimage);
+            hasher.update(preimage.as_ref());
         }
-        let bytes: [u8; crate::BYTE_LENGTH_FIELD] = hasher.finalize_fixed().into();
-        let string = format!("0x{}", hex::encode(bytes));
-        Self { bytes, string }
+        hasher.finalize()
+    }
+
+    ///
+    /// Computes the `keccak256` hash for the data read from `reader`.
+    ///
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut hasher = Keccak256Hasher::default();
+        std::io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
     }
 
     ///
@@ -47,10 +65,11 @@ impl Keccak256 {
     }
 
     ///
-    /// Returns a reference to the hexadecimal string representation of the IPFS hash.
+    /// Returns the hexadecimal string representation of the hash.
     ///
-    pub fn as_str(&self) -> &str {
-        self.string.as_str()
+    #[deprecated(note = "the string is no longer stored; use `to_string` instead")]
+    pub fn as_str(&self) -> String {
+        Hexadecimal::from(self.bytes).string
     }
 
     ///
@@ -61,9 +80,96 @@ impl Keccak256 {
     }
 }
 
+impl TryFrom<&[u8]> for Keccak256 {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; Keccak256::BYTE_LENGTH] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid Keccak-256 hash length: expected {} bytes, found {}",
+                crate::BYTE_LENGTH_FIELD,
+                bytes.len()
+            )
+        })?;
+        Ok(Self { bytes })
+    }
+}
+
+impl FromStr for Keccak256 {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses a hexadecimal string, with or without the `0x` prefix.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let bytes = crate::hash::decode_hex::<{ crate::BYTE_LENGTH_FIELD }>(string)?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for Keccak256 {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Keccak256> for Hexadecimal<{ Keccak256::BYTE_LENGTH }> {
+    fn from(hash: Keccak256) -> Self {
+        Self::from(hash.bytes)
+    }
+}
+
+impl From<Hexadecimal<{ Keccak256::BYTE_LENGTH }>> for Keccak256 {
+    fn from(representation: Hexadecimal<{ Keccak256::BYTE_LENGTH }>) -> Self {
+        Self {
+            bytes: representation.bytes,
+        }
+    }
+}
+
 impl std::fmt::Display for Keccak256 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        write!(f, "0x")?;
+        fmt_hexadecimal(f, self.as_bytes())
+    }
+}
+
+///
+/// Incremental Keccak-256 hasher.
+///
+/// Can be used as a `std::io::Write` sink to hash data while streaming it.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Keccak256Hasher {
+    /// The inner hasher state.
+    inner: sha3::Keccak256,
+}
+
+impl Keccak256Hasher {
+    ///
+    /// Appends `data` to the preimage.
+    ///
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    ///
+    /// Computes the hash of the data appended so far.
+    ///
+    pub fn finalize(self) -> Keccak256 {
+        let bytes: [u8; Keccak256::BYTE_LENGTH] = self.inner.finalize_fixed().into();
+        Keccak256 { bytes }
+    }
+}
+
+impl std::io::Write for Keccak256Hasher {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.update(buffer);
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
     }
 }
 
@@ -72,7 +178,7 @@ mod tests {
     #[test]
     fn single() {
         assert_eq!(
-            super::Keccak256::from_slice("zksync".as_bytes()).as_str(),
+            super::Keccak256::from_slice("zksync".as_bytes()).to_string(),
             "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
         );
     }
@@ -85,7 +191,7 @@ mod tests {
                 "the".as_bytes(),
                 "best".as_bytes()
             ])
-            .as_str(),
+            .to_string(),
             "0x30277e6e189b3fa474437d451ccbb2409c3b67fda53c6ad5df3f8f0f3873ff6b"
         );
     }
@@ -97,4 +203,84 @@ mod tests {
             "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
         );
     }
+
+    #[test]
+    fn hasher() {
+        let mut hasher = super::Keccak256Hasher::default();
+        hasher.update("zksync".as_bytes());
+        hasher.update("the".as_bytes());
+        hasher.update("best".as_bytes());
+
+        assert_eq!(
+            hasher.finalize(),
+            super::Keccak256::from_slice("zksyncthebest".as_bytes())
+        );
+    }
+
+    #[test]
+    fn reader() {
+        let data = vec![0xAA_u8; 1 << 20];
+
+        assert_eq!(
+            super::Keccak256::from_reader(std::io::Cursor::new(data.as_slice()))
+                .expect("Always valid"),
+            super::Keccak256::from_slice(data.as_slice())
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let hash = super::Keccak256::from_slice("zksync".as_bytes());
+
+        assert_eq!(
+            "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
+                .parse::<super::Keccak256>()
+                .expect("Always valid"),
+            hash
+        );
+        assert_eq!(
+            "0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
+                .parse::<super::Keccak256>()
+                .expect("Always valid"),
+            hash
+        );
+        assert_eq!(
+            super::Keccak256::try_from(hash.as_bytes()).expect("Always valid"),
+            hash
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let hash = super::Keccak256::from_slice("zksync".as_bytes());
+        let json = serde_json::to_value(hash).expect("Always valid");
+
+        assert_eq!(
+            json["string"],
+            "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
+        );
+        assert_eq!(
+            json["bytes"].as_array().map(Vec::len),
+            Some(super::Keccak256::BYTE_LENGTH)
+        );
+        assert_eq!(
+            serde_json::from_value::<super::Keccak256>(json).expect("Always valid"),
+            hash
+        );
+    }
+
+    #[test]
+    fn parse_error_length() {
+        assert!("0x0238fb1ab06c28c3".parse::<super::Keccak256>().is_err());
+        assert!(super::Keccak256::try_from([0_u8; 31].as_slice()).is_err());
+    }
+
+    #[test]
+    fn parse_error_hex() {
+        assert!(
+            "0xzz38fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
+                .parse::<super::Keccak256>()
+                .is_err()
+        );
+    }
 }
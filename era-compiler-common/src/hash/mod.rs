@@ -2,24 +2,112 @@
 //! Hash utilities.
 //!
 
+pub mod eravm_bytecode;
 pub mod ipfs;
 pub mod keccak256;
+pub(crate) mod representation;
+pub mod ripemd160;
+pub mod serialization;
+pub mod sha256;
+pub mod swarm;
 
+use self::eravm_bytecode::EraVMBytecode;
 use self::ipfs::IPFS;
 use self::keccak256::Keccak256;
+use self::ripemd160::Ripemd160;
+use self::sha256::Sha256;
+use self::swarm::Swarm;
+
+use crate::metadata_hash_type::MetadataHashType;
 
 ///
 /// Hash enum to make encoding easier.
 ///
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// Serialized as `{ "type": ..., "hash": ... }`, where the hash is a base58 string for IPFS,
+/// and a `0x`-prefixed hexadecimal string otherwise.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(tag = "type", content = "hash")]
 pub enum Hash {
     /// IPFS hash.
+    #[serde(rename = "ipfs", with = "self::serialization::base58_string")]
     IPFS(IPFS),
     /// Keccak256 hash.
+    #[serde(rename = "keccak256", with = "self::serialization::hex_string")]
     Keccak256(Keccak256),
+    /// SHA-256 hash.
+    #[serde(rename = "sha256", with = "self::serialization::hex_string")]
+    Sha256(Sha256),
+    /// RIPEMD-160 hash.
+    #[serde(rename = "ripemd160", with = "self::serialization::hex_string")]
+    Ripemd160(Ripemd160),
+    /// EraVM versioned bytecode hash.
+    #[serde(rename = "eravm_bytecode", with = "self::serialization::hex_string")]
+    EraVMBytecode(EraVMBytecode),
+    /// Legacy `bzzr0` Swarm hash.
+    #[serde(rename = "bzzr0", with = "self::serialization::hex_string")]
+    Swarm0(Swarm),
+    /// `bzzr1` Swarm hash.
+    #[serde(rename = "bzzr1", with = "self::serialization::hex_string")]
+    Swarm1(Swarm),
 }
 
 impl Hash {
+    ///
+    /// Parses a hash of `hash_type` from its string representation.
+    ///
+    /// See the `FromStr` implementations of the hash types for the supported formats.
+    ///
+    pub fn try_from_str(hash_type: MetadataHashType, string: &str) -> anyhow::Result<Self> {
+        match hash_type {
+            MetadataHashType::None => {
+                anyhow::bail!("Hash type `{hash_type}` cannot be parsed from a string")
+            }
+            MetadataHashType::IPFS => string.parse::<IPFS>().map(Self::IPFS),
+            MetadataHashType::Keccak256 => string.parse::<Keccak256>().map(Self::Keccak256),
+            MetadataHashType::Swarm0 => string.parse::<Swarm>().map(Self::Swarm0),
+            MetadataHashType::Swarm1 => string.parse::<Swarm>().map(Self::Swarm1),
+        }
+    }
+
+    ///
+    /// Parses a hash of `hash_type` from its binary representation.
+    ///
+    pub fn try_from_slice(hash_type: MetadataHashType, bytes: &[u8]) -> anyhow::Result<Self> {
+        match hash_type {
+            MetadataHashType::None => {
+                anyhow::bail!("Hash type `{hash_type}` cannot be parsed from bytes")
+            }
+            MetadataHashType::IPFS => IPFS::try_from(bytes).map(Self::IPFS),
+            MetadataHashType::Keccak256 => Keccak256::try_from(bytes).map(Self::Keccak256),
+            MetadataHashType::Swarm0 => Swarm::try_from(bytes).map(Self::Swarm0),
+            MetadataHashType::Swarm1 => Swarm::try_from(bytes).map(Self::Swarm1),
+        }
+    }
+
+    ///
+    /// Returns the metadata hash type of the hash, if it can be used as the metadata hash.
+    ///
+    pub fn metadata_hash_type(&self) -> Option<MetadataHashType> {
+        match self {
+            Self::IPFS(_) => Some(MetadataHashType::IPFS),
+            Self::Keccak256(_) => Some(MetadataHashType::Keccak256),
+            Self::Swarm0(_) => Some(MetadataHashType::Swarm0),
+            Self::Swarm1(_) => Some(MetadataHashType::Swarm1),
+            Self::Sha256(_) | Self::Ripemd160(_) | Self::EraVMBytecode(_) => None,
+        }
+    }
+
+    ///
+    /// Returns the string representation of the hash.
+    ///
+    #[deprecated(note = "the string is no longer stored; use `to_string` instead")]
+    pub fn as_str(&self) -> String {
+        self.to_string()
+    }
+
     ///
     /// Returns a reference to the hash bytes.
     ///
@@ -27,16 +115,31 @@ impl Hash {
         match self {
             Hash::IPFS(hash) => hash.as_bytes(),
             Hash::Keccak256(hash) => hash.as_bytes(),
+            Hash::Sha256(hash) => hash.as_bytes(),
+            Hash::Ripemd160(hash) => hash.as_bytes(),
+            Hash::EraVMBytecode(hash) => hash.as_bytes(),
+            Hash::Swarm0(hash) => hash.as_bytes(),
+            Hash::Swarm1(hash) => hash.as_bytes(),
         }
     }
+}
 
-    ///
-    /// Returns a reference to the hash string.
-    ///
-    pub fn as_str(&self) -> &str {
+impl AsRef<[u8]> for Hash {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl std::fmt::Display for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Hash::IPFS(hash) => hash.as_hex_str(),
-            Hash::Keccak256(hash) => hash.as_str(),
+            Hash::IPFS(hash) => write!(f, "{hash}"),
+            Hash::Keccak256(hash) => write!(f, "{hash}"),
+            Hash::Sha256(hash) => write!(f, "{hash}"),
+            Hash::Ripemd160(hash) => write!(f, "{hash}"),
+            Hash::EraVMBytecode(hash) => write!(f, "{hash}"),
+            Hash::Swarm0(hash) => write!(f, "{hash}"),
+            Hash::Swarm1(hash) => write!(f, "{hash}"),
         }
     }
 }
@@ -52,3 +155,99 @@ impl From<Keccak256> for Hash {
         Self::Keccak256(hash)
     }
 }
+
+impl From<Sha256> for Hash {
+    fn from(hash: Sha256) -> Self {
+        Self::Sha256(hash)
+    }
+}
+
+impl From<Ripemd160> for Hash {
+    fn from(hash: Ripemd160) -> Self {
+        Self::Ripemd160(hash)
+    }
+}
+
+impl From<EraVMBytecode> for Hash {
+    fn from(hash: EraVMBytecode) -> Self {
+        Self::EraVMBytecode(hash)
+    }
+}
+
+///
+/// Decodes a hexadecimal string of exactly `N` bytes, with or without the `0x` prefix.
+///
+pub(crate) fn decode_hex<const N: usize>(string: &str) -> anyhow::Result<[u8; N]> {
+    let stripped = string.strip_prefix("0x").unwrap_or(string);
+    let bytes = hex::decode(stripped)
+        .map_err(|error| anyhow::anyhow!("Invalid hexadecimal string `{string}`: {error}"))?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        anyhow::anyhow!(
+            "Invalid length of `{string}`: expected {N} bytes, found {}",
+            bytes.len()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata_hash_type::MetadataHashType;
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            super::Hash::try_from_str(
+                MetadataHashType::IPFS,
+                "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+            )
+            .expect("Always valid"),
+            super::Hash::IPFS(super::IPFS::from_slice("LLVM is the Endgame".as_bytes()))
+        );
+        assert_eq!(
+            super::Hash::try_from_str(
+                MetadataHashType::Keccak256,
+                "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
+            )
+            .expect("Always valid"),
+            super::Hash::Keccak256(super::Keccak256::from_slice("zksync".as_bytes()))
+        );
+        assert!(super::Hash::try_from_str(MetadataHashType::None, "").is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_string_accessors() {
+        let ipfs = super::IPFS::from_slice("LLVM is the Endgame".as_bytes());
+        let keccak256 = super::Keccak256::from_slice("zksync".as_bytes());
+
+        assert_eq!(
+            ipfs.as_base58_str(),
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U"
+        );
+        assert_eq!(
+            ipfs.as_hex_str(),
+            "122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125"
+        );
+        assert_eq!(
+            keccak256.as_str(),
+            "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b"
+        );
+        assert_eq!(super::Hash::IPFS(ipfs).as_str(), ipfs.as_hex_str());
+        assert_eq!(
+            super::Hash::Keccak256(keccak256).as_str(),
+            keccak256.as_str()
+        );
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let hash = super::Keccak256::from_slice("zksync".as_bytes());
+
+        assert_eq!(
+            super::Hash::try_from_slice(MetadataHashType::Keccak256, hash.as_bytes())
+                .expect("Always valid"),
+            super::Hash::Keccak256(hash)
+        );
+        assert!(super::Hash::try_from_slice(MetadataHashType::IPFS, hash.as_bytes()).is_err());
+    }
+}
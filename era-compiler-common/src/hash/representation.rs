@@ -0,0 +1,57 @@
+//!
+//! Serialized representation of hashes.
+//!
+
+///
+/// Serialized representation of hashes with a hexadecimal string form.
+///
+/// Preserves the serialization format of the hashes that used to store the string
+/// along with the bytes. The string is ignored on deserialization.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Hexadecimal<const N: usize> {
+    /// Binary representation.
+    #[serde(with = "serde_arrays")]
+    pub bytes: [u8; N],
+    /// Hexadecimal string representation.
+    #[serde(default)]
+    pub string: String,
+}
+
+impl<const N: usize> From<[u8; N]> for Hexadecimal<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self {
+            bytes,
+            string: format!("0x{}", hex::encode(bytes)),
+        }
+    }
+}
+
+///
+/// Serialized representation of IPFS multihashes.
+///
+/// Preserves the serialization format of the hash that used to store its base58 and
+/// hexadecimal strings along with the bytes. The strings are ignored on deserialization.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Multihash {
+    /// Binary representation.
+    #[serde(with = "serde_arrays")]
+    pub bytes: [u8; 2 + crate::BYTE_LENGTH_FIELD],
+    /// Base58 string representation.
+    #[serde(default)]
+    pub string_base58: String,
+    /// Hexadecimal string representation.
+    #[serde(default)]
+    pub string_hex: String,
+}
+
+///
+/// Writes `bytes` as a hexadecimal string without allocating.
+///
+pub fn fmt_hexadecimal(f: &mut std::fmt::Formatter, bytes: &[u8]) -> std::fmt::Result {
+    for byte in bytes.iter() {
+        write!(f, "{byte:02x}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,253 @@
+//!
+//! RIPEMD-160 hash utilities.
+//!
+
+use std::str::FromStr;
+
+use ripemd::digest::FixedOutput;
+use ripemd::Digest;
+
+use crate::hash::representation::fmt_hexadecimal;
+use crate::hash::representation::Hexadecimal;
+
+///
+/// RIPEMD-160 hash utilities.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(
+    into = "Hexadecimal<{ Ripemd160::BYTE_LENGTH }>",
+    from = "Hexadecimal<{ Ripemd160::BYTE_LENGTH }>"
+)]
+pub struct Ripemd160 {
+    /// Binary representation.
+    bytes: [u8; Self::BYTE_LENGTH],
+}
+
+impl Ripemd160 {
+    /// The RIPEMD-160 hash byte-length.
+    pub const BYTE_LENGTH: usize = 20;
+
+    ///
+    /// Computes the `ripemd160` hash for `preimage`.
+    ///
+    pub fn from_slice(preimage: &[u8]) -> Self {
+        let bytes = ripemd::Ripemd160::digest(preimage).into();
+        Self { bytes }
+    }
+
+    ///
+    /// Computes the `ripemd160` hash for an array of `preimages`.
+    ///
+    pub fn from_slices<R: AsRef<[u8]>>(preimages: &[R]) -> Self {
+        let mut hasher = Ripemd160Hasher::default();
+        for preimage in preimages.iter() {
+            hasher.update(preimage.as_ref());
+        }
+        hasher.finalize()
+    }
+
+    ///
+    /// Computes the `ripemd160` hash for the data read from `reader`.
+    ///
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut hasher = Ripemd160Hasher::default();
+        std::io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
+    }
+
+    ///
+    /// Returns a reference to the 20-byte RIPEMD-160 hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for Ripemd160 {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; Ripemd160::BYTE_LENGTH] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid RIPEMD-160 hash length: expected {} bytes, found {}",
+                Ripemd160::BYTE_LENGTH,
+                bytes.len()
+            )
+        })?;
+        Ok(Self { bytes })
+    }
+}
+
+impl FromStr for Ripemd160 {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses a hexadecimal string, with or without the `0x` prefix.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let bytes = crate::hash::decode_hex::<{ Ripemd160::BYTE_LENGTH }>(string)?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for Ripemd160 {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Ripemd160> for Hexadecimal<{ Ripemd160::BYTE_LENGTH }> {
+    fn from(hash: Ripemd160) -> Self {
+        Self::from(hash.bytes)
+    }
+}
+
+impl From<Hexadecimal<{ Ripemd160::BYTE_LENGTH }>> for Ripemd160 {
+    fn from(representation: Hexadecimal<{ Ripemd160::BYTE_LENGTH }>) -> Self {
+        Self {
+            bytes: representation.bytes,
+        }
+    }
+}
+
+impl std::fmt::Display for Ripemd160 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "0x")?;
+        fmt_hexadecimal(f, self.as_bytes())
+    }
+}
+
+///
+/// Incremental RIPEMD-160 hasher.
+///
+/// Can be used as a `std::io::Write` sink to hash data while streaming it.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Ripemd160Hasher {
+    /// The inner hasher state.
+    inner: ripemd::Ripemd160,
+}
+
+impl Ripemd160Hasher {
+    ///
+    /// Appends `data` to the preimage.
+    ///
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    ///
+    /// Computes the hash of the data appended so far.
+    ///
+    pub fn finalize(self) -> Ripemd160 {
+        let bytes: [u8; Ripemd160::BYTE_LENGTH] = self.inner.finalize_fixed().into();
+        Ripemd160 { bytes }
+    }
+}
+
+impl std::io::Write for Ripemd160Hasher {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.update(buffer);
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn empty() {
+        assert_eq!(
+            super::Ripemd160::from_slice(&[]).to_string(),
+            "0x9c1185a5c5e9fc54612808977ee8f548b2258d31"
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        assert_eq!(
+            super::Ripemd160::from_slices(&["a".as_bytes(), "b".as_bytes(), "c".as_bytes()])
+                .to_string(),
+            "0x8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            super::Ripemd160::from_slice("abc".as_bytes()).to_string(),
+            "0x8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+        );
+    }
+
+    #[test]
+    fn hasher() {
+        let mut hasher = super::Ripemd160Hasher::default();
+        hasher.update("zksync".as_bytes());
+        hasher.update("the".as_bytes());
+        hasher.update("best".as_bytes());
+
+        assert_eq!(
+            hasher.finalize(),
+            super::Ripemd160::from_slice("zksyncthebest".as_bytes())
+        );
+    }
+
+    #[test]
+    fn reader() {
+        let data = vec![0xAA_u8; 1 << 20];
+
+        assert_eq!(
+            super::Ripemd160::from_reader(std::io::Cursor::new(data.as_slice()))
+                .expect("Always valid"),
+            super::Ripemd160::from_slice(data.as_slice())
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let expected = super::Ripemd160::from_slice("abc".as_bytes());
+
+        assert_eq!(
+            "0x8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+                .parse::<super::Ripemd160>()
+                .expect("Always valid"),
+            expected
+        );
+        assert_eq!(
+            "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+                .parse::<super::Ripemd160>()
+                .expect("Always valid"),
+            expected
+        );
+        assert_eq!(
+            expected
+                .to_string()
+                .parse::<super::Ripemd160>()
+                .expect("Always valid"),
+            expected
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!("0x8eb208f7e05d987a9b044a8e98c6b087f15a0b"
+            .parse::<super::Ripemd160>()
+            .is_err());
+        assert!("0x8eb208f7e05d987a9b044a8e98c6b087f15a0bfz"
+            .parse::<super::Ripemd160>()
+            .is_err());
+    }
+}
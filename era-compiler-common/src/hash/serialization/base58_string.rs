@@ -0,0 +1,29 @@
+//!
+//! Serialization of hashes as base58 strings.
+//!
+
+use base58::ToBase58;
+
+///
+/// Serializes `hash` as a base58 string.
+///
+/// For IPFS hashes, the string is the CIDv0.
+///
+pub fn serialize<S, T>(hash: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: AsRef<[u8]>,
+{
+    serializer.serialize_str(hash.as_ref().to_base58().as_str())
+}
+
+///
+/// Deserializes a hash from either a base58 string, or a `0x`-prefixed hexadecimal string.
+///
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: for<'a> TryFrom<&'a [u8], Error = anyhow::Error>,
+{
+    super::deserialize(deserializer)
+}
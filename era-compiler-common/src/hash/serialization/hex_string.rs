@@ -0,0 +1,25 @@
+//!
+//! Serialization of hashes as `0x`-prefixed hexadecimal strings.
+//!
+
+///
+/// Serializes `hash` as a `0x`-prefixed hexadecimal string.
+///
+pub fn serialize<S, T>(hash: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: AsRef<[u8]>,
+{
+    serializer.serialize_str(format!("0x{}", hex::encode(hash.as_ref())).as_str())
+}
+
+///
+/// Deserializes a hash from either a `0x`-prefixed hexadecimal string, or a base58 string.
+///
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: for<'a> TryFrom<&'a [u8], Error = anyhow::Error>,
+{
+    super::deserialize(deserializer)
+}
@@ -0,0 +1,206 @@
+//!
+//! Serialization of hashes as strings.
+//!
+//! The submodules are meant to be used with `#[serde(with = "...")]` on fields of concrete hash types.
+//! The `Hash` enum uses them for its own `{ "type": ..., "hash": ... }` representation, as its variant
+//! cannot be recovered from the string alone. Both the hexadecimal and base58 forms are accepted on deserialization.
+//!
+
+pub mod base58_string;
+pub mod hex_string;
+
+use base58::FromBase58;
+
+///
+/// Deserializes a hash from either a `0x`-prefixed hexadecimal string, or a base58 string.
+///
+pub(crate) fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: for<'a> TryFrom<&'a [u8], Error = anyhow::Error>,
+{
+    let string = <String as serde::Deserialize>::deserialize(deserializer)?;
+    let bytes = match string.strip_prefix("0x") {
+        Some(hexadecimal) => hex::decode(hexadecimal).map_err(|error| {
+            serde::de::Error::custom(format!("Invalid hexadecimal hash `{string}`: {error}"))
+        })?,
+        None => string.from_base58().map_err(|error| {
+            serde::de::Error::custom(format!("Invalid base58 hash `{string}`: {error:?}"))
+        })?,
+    };
+    T::try_from(bytes.as_slice()).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hash::eravm_bytecode::EraVMBytecode;
+    use crate::hash::ipfs::IPFS;
+    use crate::hash::keccak256::Keccak256;
+    use crate::hash::ripemd160::Ripemd160;
+    use crate::hash::sha256::Sha256;
+    use crate::hash::swarm::Swarm;
+    use crate::hash::Hash;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Metadata {
+        #[serde(with = "super::hex_string")]
+        keccak256: Keccak256,
+        #[serde(with = "super::base58_string")]
+        ipfs: IPFS,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Hashes {
+        #[serde(with = "super::hex_string")]
+        ipfs: IPFS,
+        #[serde(with = "super::base58_string")]
+        keccak256: Keccak256,
+        #[serde(with = "super::hex_string")]
+        sha256: Sha256,
+        #[serde(with = "super::base58_string")]
+        ripemd160: Ripemd160,
+        #[serde(with = "super::hex_string")]
+        eravm_bytecode: EraVMBytecode,
+        #[serde(with = "super::base58_string")]
+        swarm: Swarm,
+    }
+
+    fn metadata() -> Metadata {
+        Metadata {
+            keccak256: Keccak256::from_slice("zksync".as_bytes()),
+            ipfs: IPFS::from_slice("LLVM is the Endgame".as_bytes()),
+        }
+    }
+
+    fn hashes() -> Vec<Hash> {
+        let preimage = "LLVM is the Endgame".as_bytes();
+        vec![
+            Hash::IPFS(IPFS::from_slice(preimage)),
+            Hash::Keccak256(Keccak256::from_slice(preimage)),
+            Hash::Sha256(Sha256::from_slice(preimage)),
+            Hash::Ripemd160(Ripemd160::from_slice(preimage)),
+            Hash::EraVMBytecode(
+                EraVMBytecode::from_slice(&[0xAA; crate::BYTE_LENGTH_FIELD]).expect("Always valid"),
+            ),
+            Hash::Swarm0(Swarm::from_slice_bzzr0(preimage)),
+            Hash::Swarm1(Swarm::from_slice_bzzr1(preimage)),
+        ]
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_value(metadata()).expect("Always valid"),
+            serde_json::json!({
+                "keccak256": "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b",
+                "ipfs": "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_both_forms() {
+        let json = serde_json::json!({
+            "keccak256": "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b",
+            "ipfs": "0x122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125",
+        });
+
+        assert_eq!(
+            serde_json::from_value::<Metadata>(json).expect("Always valid"),
+            metadata()
+        );
+    }
+
+    #[test]
+    fn round_trip_concrete_types() {
+        let preimage = "LLVM is the Endgame".as_bytes();
+        let hashes = Hashes {
+            ipfs: IPFS::from_slice(preimage),
+            keccak256: Keccak256::from_slice(preimage),
+            sha256: Sha256::from_slice(preimage),
+            ripemd160: Ripemd160::from_slice(preimage),
+            eravm_bytecode: EraVMBytecode::from_slice(&[0xAA; crate::BYTE_LENGTH_FIELD])
+                .expect("Always valid"),
+            swarm: Swarm::from_slice_bzzr1(preimage),
+        };
+
+        let json = serde_json::to_string(&hashes).expect("Always valid");
+        assert_eq!(
+            serde_json::from_str::<Hashes>(json.as_str()).expect("Always valid"),
+            hashes
+        );
+    }
+
+    #[test]
+    fn round_trip_hash_variants() {
+        for hash in hashes().into_iter() {
+            let json = serde_json::to_string(&hash).expect("Always valid");
+            assert_eq!(
+                serde_json::from_str::<Hash>(json.as_str()).expect("Always valid"),
+                hash,
+                "{json}"
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_hash_variants() {
+        for (hash, r#type) in hashes().into_iter().zip([
+            "ipfs",
+            "keccak256",
+            "sha256",
+            "ripemd160",
+            "eravm_bytecode",
+            "bzzr0",
+            "bzzr1",
+        ]) {
+            let string = match hash {
+                Hash::IPFS(hash) => hash.to_base58_string(),
+                hash => format!("0x{}", hex::encode(hash.as_bytes())),
+            };
+            assert_eq!(
+                serde_json::to_value(hash).expect("Always valid"),
+                serde_json::json!({ "type": r#type, "hash": string })
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_hash_both_forms() {
+        let hash = Hash::IPFS(IPFS::from_slice("LLVM is the Endgame".as_bytes()));
+        for string in [
+            "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U",
+            "0x122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125",
+        ] {
+            let json = serde_json::json!({ "type": "ipfs", "hash": string });
+            assert_eq!(
+                serde_json::from_value::<Hash>(json).expect("Always valid"),
+                hash
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_error() {
+        for (keccak256, ipfs) in [
+            (
+                "0x0238fb1ab06c28c3",
+                "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U",
+            ),
+            (
+                "0x0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b",
+                "Qm0",
+            ),
+            (
+                "0238fb1ab06c28c32885f9a4842207ac480c2467df26b6c58e201679628c5a5b",
+                "QmUriSv3NW33gtfyALPtD83tr1J88tQ4tGwLEVfH1wb92U",
+            ),
+        ] {
+            let json = serde_json::json!({
+                "keccak256": keccak256,
+                "ipfs": ipfs,
+            });
+            assert!(serde_json::from_value::<Metadata>(json).is_err());
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//!
+//! SHA-256 hash utilities.
+//!
+
+use std::str::FromStr;
+
+use sha2::digest::FixedOutput;
+use sha2::Digest;
+
+use crate::hash::representation::fmt_hexadecimal;
+use crate::hash::representation::Hexadecimal;
+
+///
+/// SHA-256 hash utilities.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(
+    into = "Hexadecimal<{ Sha256::BYTE_LENGTH }>",
+    from = "Hexadecimal<{ Sha256::BYTE_LENGTH }>"
+)]
+pub struct Sha256 {
+    /// Binary representation.
+    bytes: [u8; Self::BYTE_LENGTH],
+}
+
+impl Sha256 {
+    /// The SHA-256 hash byte-length.
+    pub const BYTE_LENGTH: usize = crate::BYTE_LENGTH_FIELD;
+
+    ///
+    /// Computes the `sha256` hash for `preimage`.
+    ///
+    pub fn from_slice(preimage: &[u8]) -> Self {
+        let bytes = sha2::Sha256::digest(preimage).into();
+        Self { bytes }
+    }
+
+    ///
+    /// Computes the `sha256` hash for an array of `preimages`.
+    ///
+    pub fn from_slices<R: AsRef<[u8]>>(preimages: &[R]) -> Self {
+        let mut hasher = Sha256Hasher::default();
+        for preimage in preimages.iter() {
+            hasher.update(preimage.as_ref());
+        }
+        hasher.finalize()
+    }
+
+    ///
+    /// Computes the `sha256` hash for the data read from `reader`.
+    ///
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut hasher = Sha256Hasher::default();
+        std::io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
+    }
+
+    ///
+    /// Returns a reference to the 32-byte SHA-256 hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for Sha256 {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; Sha256::BYTE_LENGTH] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid SHA-256 hash length: expected {} bytes, found {}",
+                Sha256::BYTE_LENGTH,
+                bytes.len()
+            )
+        })?;
+        Ok(Self { bytes })
+    }
+}
+
+impl FromStr for Sha256 {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses a hexadecimal string, with or without the `0x` prefix.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let bytes = crate::hash::decode_hex::<{ crate::BYTE_LENGTH_FIELD }>(string)?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for Sha256 {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Sha256> for Hexadecimal<{ Sha256::BYTE_LENGTH }> {
+    fn from(hash: Sha256) -> Self {
+        Self::from(hash.bytes)
+    }
+}
+
+impl From<Hexadecimal<{ Sha256::BYTE_LENGTH }>> for Sha256 {
+    fn from(representation: Hexadecimal<{ Sha256::BYTE_LENGTH }>) -> Self {
+        Self {
+            bytes: representation.bytes,
+        }
+    }
+}
+
+impl std::fmt::Display for Sha256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "0x")?;
+        fmt_hexadecimal(f, self.as_bytes())
+    }
+}
+
+///
+/// Incremental SHA-256 hasher.
+///
+/// Can be used as a `std::io::Write` sink to hash data while streaming it.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Sha256Hasher {
+    /// The inner hasher state.
+    inner: sha2::Sha256,
+}
+
+impl Sha256Hasher {
+    ///
+    /// Appends `data` to the preimage.
+    ///
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    ///
+    /// Computes the hash of the data appended so far.
+    ///
+    pub fn finalize(self) -> Sha256 {
+        let bytes: [u8; Sha256::BYTE_LENGTH] = self.inner.finalize_fixed().into();
+        Sha256 { bytes }
+    }
+}
+
+impl std::io::Write for Sha256Hasher {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.update(buffer);
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn single() {
+        assert_eq!(
+            super::Sha256::from_slice("zksync".as_bytes()).to_string(),
+            "0x9ddc187935a26ef98ec75a49845ac90fa8e4c129708954112c2df443002d83e7"
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        assert_eq!(
+            super::Sha256::from_slices(&["zksync".as_bytes(), "the".as_bytes(), "best".as_bytes()])
+                .to_string(),
+            "0xa9a44e570f8064401bfa6508293eac5b465b535b3e81ea9a20aa897bf633f6e3"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            super::Sha256::from_slice("abc".as_bytes()).to_string(),
+            "0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn hasher() {
+        let mut hasher = super::Sha256Hasher::default();
+        hasher.update("zksync".as_bytes());
+        hasher.update("the".as_bytes());
+        hasher.update("best".as_bytes());
+
+        assert_eq!(
+            hasher.finalize(),
+            super::Sha256::from_slice("zksyncthebest".as_bytes())
+        );
+    }
+
+    #[test]
+    fn reader() {
+        let data = vec![0xAA_u8; 1 << 20];
+
+        assert_eq!(
+            super::Sha256::from_reader(std::io::Cursor::new(data.as_slice()))
+                .expect("Always valid"),
+            super::Sha256::from_slice(data.as_slice())
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let expected = super::Sha256::from_slice("zksync".as_bytes());
+
+        assert_eq!(
+            "0x9ddc187935a26ef98ec75a49845ac90fa8e4c129708954112c2df443002d83e7"
+                .parse::<super::Sha256>()
+                .expect("Always valid"),
+            expected
+        );
+        assert_eq!(
+            "9ddc187935a26ef98ec75a49845ac90fa8e4c129708954112c2df443002d83e7"
+                .parse::<super::Sha256>()
+                .expect("Always valid"),
+            expected
+        );
+        assert_eq!(
+            expected
+                .to_string()
+                .parse::<super::Sha256>()
+                .expect("Always valid"),
+            expected
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!(
+            "0x9ddc187935a26ef98ec75a49845ac90fa8e4c129708954112c2df443002d83"
+                .parse::<super::Sha256>()
+                .is_err()
+        );
+        assert!(
+            "0x9ddc187935a26ef98ec75a49845ac90fa8e4c129708954112c2df443002d83ez"
+                .parse::<super::Sha256>()
+                .is_err()
+        );
+    }
+}
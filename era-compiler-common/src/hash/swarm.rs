@@ -0,0 +1,277 @@
+//!
+//! Swarm hash utilities.
+//!
+
+use crate::hash::keccak256::Keccak256;
+use crate::hash::keccak256::Keccak256Hasher;
+use crate::hash::representation::fmt_hexadecimal;
+use crate::hash::representation::Hexadecimal;
+
+///
+/// Swarm hash utilities.
+///
+/// Implements the Swarm hashes emitted by solc in the `bzzr0` and `bzzr1` metadata fields.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(
+    into = "Hexadecimal<{ Swarm::BYTE_LENGTH }>",
+    from = "Hexadecimal<{ Swarm::BYTE_LENGTH }>"
+)]
+pub struct Swarm {
+    /// Binary representation.
+    bytes: [u8; Self::BYTE_LENGTH],
+}
+
+impl Swarm {
+    /// The Swarm hash byte-length.
+    pub const BYTE_LENGTH: usize = crate::BYTE_LENGTH_FIELD;
+
+    /// The Swarm chunk size.
+    pub const CHUNK_SIZE: usize = 0x1000;
+
+    /// The maximum number of child hashes in an intermediate chunk.
+    pub const BRANCHES: usize = Self::CHUNK_SIZE / Self::BYTE_LENGTH;
+
+    /// The size of the BMT leaf segment pairs.
+    pub const BMT_SEGMENT_PAIR_SIZE: usize = 2 * Self::BYTE_LENGTH;
+
+    ///
+    /// Computes the legacy `bzzr0` Swarm hash of `preimage`.
+    ///
+    /// Chunks are hashed as a whole, prefixed with the size of the data they represent.
+    ///
+    pub fn from_slice_bzzr0(preimage: &[u8]) -> Self {
+        Self {
+            bytes: Self::bzzr0_chunk(preimage),
+        }
+    }
+
+    ///
+    /// Computes the `bzzr1` Swarm hash of `preimage`.
+    ///
+    /// Chunks are zero-padded to `CHUNK_SIZE` and hashed with the binary Merkle tree (BMT) hash,
+    /// prefixed with the size of the data they represent.
+    ///
+    pub fn from_slice_bzzr1(preimage: &[u8]) -> Self {
+        Self {
+            bytes: Self::bzzr1_chunk(preimage, false),
+        }
+    }
+
+    ///
+    /// Returns a reference to the 32-byte Swarm hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    ///
+    /// Hashes a `bzzr0` chunk representing `data`.
+    ///
+    fn bzzr0_chunk(data: &[u8]) -> [u8; Self::BYTE_LENGTH] {
+        let mut hasher = Keccak256Hasher::default();
+        hasher.update((data.len() as u64).to_le_bytes().as_slice());
+        if data.len() <= Self::CHUNK_SIZE {
+            hasher.update(data);
+        } else {
+            for subtree in data.chunks(Self::subtree_size(data.len())) {
+                hasher.update(Self::bzzr0_chunk(subtree).as_slice());
+            }
+        }
+        Self::into_array(hasher.finalize())
+    }
+
+    ///
+    /// Hashes a `bzzr1` chunk representing `data`.
+    ///
+    /// If `force_intermediate` is set, a full chunk of data is wrapped into an intermediate chunk,
+    /// as it is done for the subtrees of larger intermediate chunks.
+    ///
+    fn bzzr1_chunk(data: &[u8], force_intermediate: bool) -> [u8; Self::BYTE_LENGTH] {
+        let mut chunk = Vec::with_capacity(Self::CHUNK_SIZE);
+        if data.len() < Self::CHUNK_SIZE || (data.len() == Self::CHUNK_SIZE && !force_intermediate)
+        {
+            chunk.extend_from_slice(data);
+        } else {
+            let subtree_size = Self::subtree_size(data.len());
+            let force_intermediate = subtree_size > Self::CHUNK_SIZE;
+            for subtree in data.chunks(subtree_size) {
+                chunk.extend_from_slice(Self::bzzr1_chunk(subtree, force_intermediate).as_slice());
+            }
+        }
+        chunk.resize(Self::CHUNK_SIZE, 0);
+
+        let mut hasher = Keccak256Hasher::default();
+        hasher.update((data.len() as u64).to_le_bytes().as_slice());
+        hasher.update(Self::bmt(chunk.as_slice()).as_slice());
+        Self::into_array(hasher.finalize())
+    }
+
+    ///
+    /// Computes the binary Merkle tree hash of `data`.
+    ///
+    fn bmt(data: &[u8]) -> [u8; Self::BYTE_LENGTH] {
+        if data.len() <= Self::BMT_SEGMENT_PAIR_SIZE {
+            return Self::into_array(Keccak256::from_slice(data));
+        }
+        let (left, right) = data.split_at(data.len() / 2);
+        Self::into_array(Keccak256::from_slices(&[Self::bmt(left), Self::bmt(right)]))
+    }
+
+    ///
+    /// Returns the size of data represented by each child of an intermediate chunk representing `size` bytes.
+    ///
+    fn subtree_size(size: usize) -> usize {
+        let mut subtree_size = Self::CHUNK_SIZE;
+        while subtree_size * Self::BRANCHES < size {
+            subtree_size *= Self::BRANCHES;
+        }
+        subtree_size
+    }
+
+    ///
+    /// Converts a Keccak-256 hash into a fixed-size array.
+    ///
+    fn into_array(hash: Keccak256) -> [u8; Self::BYTE_LENGTH] {
+        hash.as_bytes().try_into().expect("Always valid")
+    }
+}
+
+impl TryFrom<&[u8]> for Swarm {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; Swarm::BYTE_LENGTH] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid Swarm hash length: expected {} bytes, found {}",
+                Swarm::BYTE_LENGTH,
+                bytes.len()
+            )
+        })?;
+        Ok(Self { bytes })
+    }
+}
+
+impl std::str::FromStr for Swarm {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses a hexadecimal string, with or without the `0x` prefix.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let bytes = crate::hash::decode_hex::<{ Swarm::BYTE_LENGTH }>(string)?;
+        Ok(Self { bytes })
+    }
+}
+
+impl AsRef<[u8]> for Swarm {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Swarm> for Hexadecimal<{ Swarm::BYTE_LENGTH }> {
+    fn from(hash: Swarm) -> Self {
+        Self::from(hash.bytes)
+    }
+}
+
+impl From<Hexadecimal<{ Swarm::BYTE_LENGTH }>> for Swarm {
+    fn from(representation: Hexadecimal<{ Swarm::BYTE_LENGTH }>) -> Self {
+        Self {
+            bytes: representation.bytes,
+        }
+    }
+}
+
+impl std::fmt::Display for Swarm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "0x")?;
+        fmt_hexadecimal(f, self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hash::keccak256::Keccak256;
+
+    #[test]
+    fn bzzr0_empty() {
+        assert_eq!(
+            super::Swarm::from_slice_bzzr0(&[]).to_string(),
+            "0x011b4d03dd8c01f1049143cf9c4c817e4b167f1d1b83e5c6f0f10d89ba1e7bce"
+        );
+    }
+
+    #[test]
+    fn bzzr0_zeros() {
+        assert_eq!(
+            super::Swarm::from_slice_bzzr0(vec![0_u8; super::Swarm::CHUNK_SIZE - 1].as_slice())
+                .to_string(),
+            "0x32f0faabc4265ac238cd945087133ce3d7e9bb2e536053a812b5373c54043adb"
+        );
+    }
+
+    #[test]
+    fn bzzr1_empty() {
+        assert_eq!(
+            super::Swarm::from_slice_bzzr1(&[]).to_string(),
+            "0xb34ca8c22b9e982354f9c7f50b470d66db428d880c8a904d5fe4ec9713171526"
+        );
+    }
+
+    #[test]
+    fn bzzr1_zeros() {
+        assert_eq!(
+            super::Swarm::from_slice_bzzr1(vec![0_u8; super::Swarm::CHUNK_SIZE].as_slice())
+                .to_string(),
+            "0x09ae927d0f3aaa37324df178928d3826820f3dd3388ce4aaebfc3af410bde23a"
+        );
+        assert_eq!(
+            super::Swarm::from_slice_bzzr1(vec![0_u8; super::Swarm::CHUNK_SIZE + 1].as_slice())
+                .to_string(),
+            "0xc082943c4cb8a97c67947f290f5421cf4c61d021eb303c8df77de6fe208df516"
+        );
+    }
+
+    #[test]
+    fn bzzr1_single_chunk() {
+        let data = "LLVM is the Endgame".as_bytes();
+        let mut chunk = data.to_vec();
+        chunk.resize(super::Swarm::CHUNK_SIZE, 0);
+
+        assert_eq!(
+            super::Swarm::from_slice_bzzr1(data).as_bytes(),
+            Keccak256::from_slices(&[
+                (data.len() as u64).to_le_bytes().as_slice(),
+                super::Swarm::bmt(chunk.as_slice()).as_slice(),
+            ])
+            .as_bytes()
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let hash = super::Swarm::from_slice_bzzr0("LLVM is the Endgame".as_bytes());
+
+        assert_eq!(
+            hash.to_string()
+                .parse::<super::Swarm>()
+                .expect("Always valid"),
+            hash
+        );
+        assert_eq!(
+            super::Swarm::try_from(hash.as_bytes()).expect("Always valid"),
+            hash
+        );
+    }
+}
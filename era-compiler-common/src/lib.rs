@@ -6,6 +6,7 @@ pub(crate) mod address;
 pub(crate) mod base;
 pub(crate) mod bit_length;
 pub(crate) mod byte_length;
+pub(crate) mod bytecode;
 pub(crate) mod cbor;
 pub(crate) mod code_segment;
 pub(crate) mod contract_name;
@@ -16,21 +17,44 @@ pub(crate) mod hash;
 pub(crate) mod libraries;
 pub(crate) mod metadata_hash_type;
 pub(crate) mod object_format;
+pub(crate) mod string_enum;
 pub(crate) mod target;
 pub(crate) mod utils;
 
+pub use self::address::eravm_simulation::EraVMSimulation;
+pub use self::address::eravm_system_contract::EraVMSystemContract;
 pub use self::address::*;
 pub use self::base::*;
 pub use self::bit_length::*;
 pub use self::byte_length::*;
+pub use self::bytecode::*;
+pub use self::cbor::error::Error as CBORError;
+pub use self::cbor::layout::Layout as CBORLayout;
+pub use self::cbor::value::Value as CBORValue;
 pub use self::cbor::CBOR;
 pub use self::code_segment::CodeSegment;
 pub use self::contract_name::ContractName;
+pub use self::evm_version::feature::EVMFeature;
+pub use self::evm_version::gas_schedule::GasSchedule as EVMGasSchedule;
 pub use self::evm_version::EVMVersion;
 pub use self::exit_code::*;
 pub use self::extension::*;
+pub use self::hash::eravm_bytecode::EraVMBytecode as EraVMBytecodeHash;
+pub use self::hash::ipfs::cid::Version as IPFSCIDVersion;
+pub use self::hash::ipfs::cid::CID as IPFSCID;
+pub use self::hash::ipfs::codec::Codec as IPFSCodec;
+pub use self::hash::ipfs::unixfs::Builder as IPFSUnixFSBuilder;
+pub use self::hash::ipfs::IPFSHasher;
 pub use self::hash::ipfs::IPFS as IPFSHash;
 pub use self::hash::keccak256::Keccak256 as Keccak256Hash;
+pub use self::hash::keccak256::Keccak256Hasher;
+pub use self::hash::ripemd160::Ripemd160 as Ripemd160Hash;
+pub use self::hash::ripemd160::Ripemd160Hasher;
+pub use self::hash::serialization::base58_string as hash_base58_string;
+pub use self::hash::serialization::hex_string as hash_hex_string;
+pub use self::hash::sha256::Sha256 as Sha256Hash;
+pub use self::hash::sha256::Sha256Hasher;
+pub use self::hash::swarm::Swarm as SwarmHash;
 pub use self::hash::Hash;
 pub use self::libraries::Libraries;
 pub use self::metadata_hash_type::MetadataHashType;
@@ -4,6 +4,9 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use crate::address::Address;
 
 ///
 /// The unified representation of Solidity libraries.
@@ -19,6 +22,9 @@ impl Libraries {
     ///
     /// Returns a representation of libraries suitable for the LLVM linker.
     ///
+    /// Mixed-case addresses must have a valid EIP-55 checksum, whereas all-lowercase
+    /// and all-uppercase addresses are accepted without checksum validation.
+    ///
     pub fn as_linker_symbols(
         &self,
     ) -> anyhow::Result<BTreeMap<String, [u8; crate::BYTE_LENGTH_ETH_ADDRESS]>> {
@@ -27,19 +33,11 @@ impl Libraries {
             for (name, address) in contracts.iter() {
                 let path = format!("{file}:{name}");
 
-                let address_stripped = address.strip_prefix("0x").unwrap_or(address.as_str());
-                let address_vec = hex::decode(address_stripped).map_err(|error| {
-                    anyhow::anyhow!("Invalid address `{address}` of library `{path}`: {error}.")
-                })?;
-                let address_array: [u8; crate::BYTE_LENGTH_ETH_ADDRESS] = address_vec.try_into().map_err(|address_vec: Vec<u8>| {
-                    anyhow::anyhow!(
-                        "Incorrect size of address `{address}` of library `{path}`: expected {}, found {}.",
-                        crate::BYTE_LENGTH_ETH_ADDRESS,
-                        address_vec.len(),
-                    )
+                let address = Address::from_str(address.as_str()).map_err(|error| {
+                    anyhow::anyhow!("Invalid address of library `{path}`: {error}.")
                 })?;
 
-                linker_symbols.insert(path, address_array);
+                linker_symbols.insert(path, address.into());
             }
         }
         Ok(linker_symbols)
@@ -116,3 +114,71 @@ impl TryFrom<&[String]> for Libraries {
         Ok(Self { inner: libraries })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    fn libraries(address: &str) -> super::Libraries {
+        super::Libraries::try_from(
+            [
+                "Library.sol:Math=0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_owned(),
+                format!("Library.sol:Strings={address}"),
+            ]
+            .as_slice(),
+        )
+        .expect("Always valid")
+    }
+
+    #[test]
+    fn linker_symbols() {
+        let expected = BTreeMap::from([
+            (
+                "Library.sol:Math".to_owned(),
+                hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")
+                    .expect("Always valid")
+                    .try_into()
+                    .expect("Always valid"),
+            ),
+            (
+                "Library.sol:Strings".to_owned(),
+                hex::decode("fb6916095ca1df60bb79ce92ce3ea74c37c5d359")
+                    .expect("Always valid")
+                    .try_into()
+                    .expect("Always valid"),
+            ),
+        ]);
+
+        for address in [
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359",
+            "FB6916095CA1DF60BB79CE92CE3EA74C37C5D359",
+        ] {
+            assert_eq!(
+                libraries(address)
+                    .as_linker_symbols()
+                    .expect("Always valid"),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn linker_symbols_error_checksum() {
+        let error = libraries("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d35A")
+            .as_linker_symbols()
+            .expect_err("Always invalid");
+
+        assert!(error.to_string().contains("Library.sol:Strings"));
+        assert!(error.to_string().contains("checksum"));
+    }
+
+    #[test]
+    fn linker_symbols_error_length() {
+        let error = libraries("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d3")
+            .as_linker_symbols()
+            .expect_err("Always invalid");
+
+        assert!(error.to_string().contains("Library.sol:Strings"));
+    }
+}
@@ -2,43 +2,157 @@
 //! Metadata hash type.
 //!
 
-use std::str::FromStr;
-
-///
-/// Metadata hash type.
-///
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum MetadataHashType {
-    /// Do not include bytecode hash.
-    #[serde(rename = "none")]
-    None,
-    /// The `ipfs` hash.
-    #[serde(rename = "ipfs")]
-    IPFS,
-    /// The `keccak256`` hash type.
-    #[serde(rename = "keccak256")]
-    Keccak256,
-}
+use crate::cbor::error::Error as CBORError;
+use crate::cbor::CBOR;
+use crate::hash::ipfs::IPFS;
+use crate::hash::keccak256::Keccak256;
+use crate::hash::swarm::Swarm;
+use crate::hash::Hash;
+use crate::string_enum::string_enum;
 
-impl FromStr for MetadataHashType {
-    type Err = anyhow::Error;
+string_enum! {
+    ///
+    /// Metadata hash type.
+    ///
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    pub enum MetadataHashType("metadata hash type") {
+        /// Do not include bytecode hash.
+        None => "none",
+        /// The `ipfs` hash.
+        IPFS => "ipfs",
+        /// The `keccak256` hash type.
+        Keccak256 => "keccak256",
+        /// The legacy `bzzr0` Swarm hash.
+        Swarm0 => "bzzr0",
+        /// The `bzzr1` Swarm hash.
+        Swarm1 => "bzzr1",
+    }
+}
 
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        match string {
-            "none" => Ok(Self::None),
-            "ipfs" => Ok(Self::IPFS),
-            "keccak256" => Ok(Self::Keccak256),
-            string => anyhow::bail!("unknown bytecode hash mode: `{string}`"),
+impl MetadataHashType {
+    ///
+    /// Computes the hash of `metadata`.
+    ///
+    /// Returns `None` if the hash type is `None`.
+    ///
+    pub fn hash(&self, metadata: &[u8]) -> Option<Hash> {
+        match self {
+            Self::None => None,
+            Self::IPFS => Some(Hash::IPFS(IPFS::from_slice(metadata))),
+            Self::Keccak256 => Some(Hash::Keccak256(Keccak256::from_slice(metadata))),
+            Self::Swarm0 => Some(Hash::Swarm0(Swarm::from_slice_bzzr0(metadata))),
+            Self::Swarm1 => Some(Hash::Swarm1(Swarm::from_slice_bzzr1(metadata))),
         }
     }
+
+    ///
+    /// Computes the hash of `metadata` and the CBOR trailer to append to the bytecode.
+    ///
+    /// The trailer contains the hash under the hash type key, followed by the version data.
+    /// If the hash type is `None`, the trailer contains only the version data.
+    ///
+    pub fn hash_with_cbor(
+        &self,
+        metadata: &[u8],
+        version_key: String,
+        version_data: Vec<(String, semver::Version)>,
+    ) -> Result<(Option<Hash>, Vec<u8>), CBORError> {
+        let hash = self.hash(metadata);
+        let cbor = CBOR::try_new(
+            hash.as_ref()
+                .map(|hash| (self.to_string(), hash.as_bytes())),
+            version_key,
+            version_data,
+        )?
+        .try_to_vec()?;
+        Ok((hash, cbor))
+    }
 }
 
-impl std::fmt::Display for MetadataHashType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::None => write!(f, "none"),
-            Self::IPFS => write!(f, "ipfs"),
-            Self::Keccak256 => write!(f, "keccak256"),
+#[cfg(test)]
+mod tests {
+    use crate::cbor::CBOR;
+    use crate::hash::ipfs::IPFS;
+    use crate::hash::keccak256::Keccak256;
+    use crate::hash::Hash;
+
+    const METADATA: &str = r#"{"language":"Solidity"}"#;
+
+    fn version_data() -> Vec<(String, semver::Version)> {
+        vec![
+            ("zksolc".to_owned(), semver::Version::new(1, 5, 7)),
+            ("solc".to_owned(), semver::Version::new(0, 8, 28)),
+        ]
+    }
+
+    #[test]
+    fn none() {
+        let (hash, cbor) = super::MetadataHashType::None
+            .hash_with_cbor(METADATA.as_bytes(), "solc".to_owned(), version_data())
+            .expect("Always valid");
+
+        assert_eq!(hash, None);
+        assert_eq!(
+            cbor,
+            CBOR::<'_, String>::new(None, "solc".to_owned(), version_data()).to_vec()
+        );
+    }
+
+    #[test]
+    fn ipfs() {
+        let (hash, cbor) = super::MetadataHashType::IPFS
+            .hash_with_cbor(METADATA.as_bytes(), "solc".to_owned(), version_data())
+            .expect("Always valid");
+
+        let expected = IPFS::from_slice(METADATA.as_bytes());
+        assert_eq!(hash, Some(Hash::IPFS(expected)));
+
+        let decoded = CBOR::from_slice(cbor.as_slice()).expect("Always valid");
+        assert_eq!(decoded.hash, Some(("ipfs".to_owned(), expected.as_bytes())));
+        assert_eq!(decoded.version_data, version_data());
+    }
+
+    #[test]
+    fn keccak256() {
+        let (hash, cbor) = super::MetadataHashType::Keccak256
+            .hash_with_cbor(METADATA.as_bytes(), "solc".to_owned(), version_data())
+            .expect("Always valid");
+
+        let expected = Keccak256::from_slice(METADATA.as_bytes());
+        assert_eq!(hash, Some(Hash::Keccak256(expected)));
+        assert_eq!(
+            CBOR::from_slice(cbor.as_slice())
+                .expect("Always valid")
+                .hash,
+            Some(("keccak256".to_owned(), expected.as_bytes()))
+        );
+    }
+
+    #[test]
+    fn swarm() {
+        for (hash_type, key) in [
+            (super::MetadataHashType::Swarm0, "bzzr0"),
+            (super::MetadataHashType::Swarm1, "bzzr1"),
+        ] {
+            let (hash, cbor) = hash_type
+                .hash_with_cbor(METADATA.as_bytes(), "solc".to_owned(), version_data())
+                .expect("Always valid");
+            let hash = hash.expect("Always exists");
+
+            assert_eq!(key.parse::<super::MetadataHashType>().ok(), Some(hash_type));
+            assert_eq!(
+                CBOR::from_slice(cbor.as_slice())
+                    .expect("Always valid")
+                    .hash,
+                Some((key.to_owned(), hash.as_bytes()))
+            );
         }
     }
+
+    #[test]
+    fn error_empty_version_data() {
+        assert!(super::MetadataHashType::IPFS
+            .hash_with_cbor(METADATA.as_bytes(), "solc".to_owned(), vec![])
+            .is_err());
+    }
 }
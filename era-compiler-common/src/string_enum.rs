@@ -0,0 +1,84 @@
+//!
+//! String-backed enumeration.
+//!
+
+///
+/// Declares an enumeration whose variants are mapped to strings by a single table.
+///
+/// The table is the only source of the serde names, `ALL`, `as_str`, `FromStr`, `Display`,
+/// and the list of supported values in the parsing error, so they cannot drift apart.
+/// The enumeration must derive `serde::Serialize` and `serde::Deserialize`.
+///
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $visibility:vis enum $name:ident($description:literal) {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $string:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        $visibility enum $name {
+            $(
+                $(#[$variant_meta])*
+                #[serde(rename = $string)]
+                $variant,
+            )+
+        }
+
+        impl $name {
+            /// All the variants, in the order of declaration.
+            pub const ALL: [Self; [$($string),+].len()] = [$(Self::$variant),+];
+
+            ///
+            /// Returns the string representation.
+            ///
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $string,)+
+                }
+            }
+
+            ///
+            /// Returns an iterator over all the variants.
+            ///
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.into_iter()
+            }
+
+            ///
+            /// Returns the comma-separated list of supported values.
+            ///
+            pub fn supported_values() -> String {
+                Self::ALL.map(|value| value.as_str()).join(", ")
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = anyhow::Error;
+
+            fn from_str(string: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .into_iter()
+                    .find(|value| value.as_str() == string)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Unknown {} `{string}`. Supported values: {}",
+                            $description,
+                            Self::supported_values(),
+                        )
+                    })
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;